@@ -1,29 +1,95 @@
-pub(crate) mod grid;
-pub(crate) mod maze;
-pub(crate) mod sep;
+pub mod cancel;
+pub mod grid;
+pub mod maze;
+pub mod parse;
+pub mod search;
+pub mod sep;
+pub mod solution;
 
-pub(crate) mod day_01;
-pub(crate) mod day_02;
-pub(crate) mod day_03;
-pub(crate) mod day_04;
-pub(crate) mod day_05;
-pub(crate) mod day_06;
-pub(crate) mod day_07;
-pub(crate) mod day_08;
-pub(crate) mod day_09;
-pub(crate) mod day_10;
-pub(crate) mod day_11;
-pub(crate) mod day_12;
-pub(crate) mod day_13;
-pub(crate) mod day_14;
-pub(crate) mod day_15;
-pub(crate) mod day_16;
-pub(crate) mod day_17;
-pub(crate) mod day_18;
-pub(crate) mod day_19;
-pub(crate) mod day_20;
-pub(crate) mod day_21;
-pub(crate) mod day_22;
-pub(crate) mod day_23;
-pub(crate) mod day_24;
-pub(crate) mod day_25;
+#[cfg(feature = "days")]
+pub mod day_01;
+#[cfg(feature = "days")]
+pub mod day_02;
+#[cfg(feature = "days")]
+pub mod day_03;
+#[cfg(feature = "days")]
+pub mod day_04;
+#[cfg(feature = "days")]
+pub mod day_05;
+#[cfg(feature = "days")]
+pub mod day_06;
+#[cfg(feature = "days")]
+pub mod day_07;
+#[cfg(feature = "days")]
+pub mod day_08;
+#[cfg(feature = "days")]
+pub mod day_09;
+#[cfg(feature = "days")]
+pub mod day_10;
+#[cfg(feature = "days")]
+pub mod day_11;
+#[cfg(feature = "days")]
+pub mod day_12;
+#[cfg(feature = "days")]
+pub mod day_13;
+#[cfg(feature = "days")]
+pub mod day_14;
+#[cfg(feature = "days")]
+pub mod day_15;
+#[cfg(feature = "days")]
+pub mod day_16;
+#[cfg(feature = "days")]
+pub mod day_17;
+#[cfg(feature = "days")]
+pub mod day_18;
+#[cfg(feature = "days")]
+pub mod day_19;
+#[cfg(feature = "days")]
+pub mod day_20;
+#[cfg(feature = "days")]
+pub mod day_21;
+#[cfg(feature = "days")]
+pub mod day_22;
+#[cfg(feature = "days")]
+pub mod day_23;
+#[cfg(feature = "days")]
+pub mod day_24;
+#[cfg(feature = "days")]
+pub mod day_25;
+
+#[cfg(feature = "days")]
+use solution::Solver;
+
+#[cfg(feature = "days")]
+pub const SOLVERS: &[&dyn Solver] = &[
+    &day_01::Day01,
+    &day_02::Day02,
+    &day_03::Day03,
+    &day_04::Day04,
+    &day_05::Day05,
+    &day_06::Day06,
+    &day_07::Day07,
+    &day_08::Day08,
+    &day_09::Day09,
+    &day_10::Day10,
+    &day_11::Day11,
+    &day_12::Day12,
+    &day_13::Day13,
+    &day_14::Day14,
+    &day_15::Day15,
+    &day_16::Day16,
+    &day_17::Day17,
+    &day_18::Day18,
+    &day_19::Day19,
+    &day_20::Day20,
+    &day_21::Day21,
+    &day_22::Day22,
+    &day_23::Day23,
+    &day_24::Day24,
+    &day_25::Day25
+];
+
+#[cfg(feature = "days")]
+pub fn get_solver(day: u8) -> Option<&'static dyn Solver> {
+    SOLVERS.iter().find(|s| s.day() == day).copied()
+}
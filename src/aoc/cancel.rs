@@ -0,0 +1,64 @@
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+// the message of the panic unwinding a cancelled part
+pub const CANCELLED: &str = "cancelled";
+
+// cooperative cancellation, the runner setting a token on the thread of each part
+#[derive(Clone, Debug, Default)]
+pub struct Token(Arc<AtomicBool>);
+
+impl Token {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+thread_local! {
+    static TOKEN: RefCell<Option<Token>> = const { RefCell::new(None) };
+}
+
+pub fn set_token(token: Option<Token>) {
+    TOKEN.with(|t| *t.borrow_mut() = token);
+}
+
+// false without a token, e.g. in the unit tests
+pub fn is_cancelled() -> bool {
+    TOKEN.with(|t| t.borrow().as_ref().is_some_and(|t| t.is_cancelled()))
+}
+
+// for the long loops of the solvers, unwinding them once cancelled
+pub fn check() {
+    if is_cancelled() {
+        panic!("{CANCELLED}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token() {
+        assert!(!is_cancelled());
+        let token = Token::new();
+        set_token(Some(token.clone()));
+        check();
+        token.cancel();
+        assert!(is_cancelled());
+        assert!(std::panic::catch_unwind(check).is_err());
+        // other threads have their own token
+        assert!(!std::thread::spawn(is_cancelled).join().unwrap());
+        set_token(None);
+        assert!(!is_cancelled());
+    }
+}
@@ -1,23 +1,26 @@
 use std::collections::HashMap;
+use crate::aoc::parse::{parse_at, ParseError};
+use crate::aoc::solution::{Example, Solution};
 
-struct Puzzle {
+pub struct Puzzle {
     locations: Vec<(u32, u32)>
 }
 
 impl Puzzle {
-    fn parse(data: &str) -> Option<Self> {
-        let parse_locations = |s: &str| {
+    fn parse(data: &str) -> Result<Self, ParseError> {
+        let parse_locations = |s: &str| -> Result<_, ParseError> {
             let mut vs = s.split_ascii_whitespace();
-            let v0 = vs.next()?.parse().ok()?;
-            let v1 = vs.next()?.parse().ok()?;
-            if vs.next().is_some() {return None;}
-            Some((v0, v1))
+            let v0 = vs.next().ok_or_else(|| ParseError::at(data, s, "a location id"))?;
+            let v1 = vs.next().ok_or_else(|| ParseError::after(data, v0, "a second location id"))?;
+            if let Some(v) = vs.next() {return Err(ParseError::at(data, v, "an end of line"));}
+            Ok((parse_at(data, v0, "a location id")?, parse_at(data, v1, "a location id")?))
         };
-        let locations: Option<_> = data.trim().lines().map(parse_locations).collect();
+        let locations: Result<_, _> = data.trim().lines().map(parse_locations).collect();
         let locations = locations?;
-        Some(Puzzle {locations})
+        Ok(Puzzle {locations})
     }
 
+    #[cfg(test)]
     fn load(data: &str) -> Self {
         Self::parse(data).expect("valid input")
     }
@@ -44,28 +47,45 @@ fn part_2(puzzle: &Puzzle) -> u32 {
     distance
 }
 
-pub(crate) fn solve() {
-    let data = include_str!("../../data/day_01/input.txt");
-    let puzzle = Puzzle::load(data);
-    println!("part 1: {}", part_1(&puzzle));
-    println!("part 2: {}", part_2(&puzzle));
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const EXAMPLES: &'static [Example] = &[Example::new("test", Some("11"), Some("31"))];
+
+    type Puzzle = Puzzle;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(data: &str) -> Result<Puzzle, ParseError> {
+        Puzzle::parse(data)
+    }
+
+    fn part_1(puzzle: &Puzzle) -> u32 {
+        part_1(puzzle)
+    }
+
+    fn part_2(puzzle: &Puzzle) -> u32 {
+        part_2(puzzle)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc::solution::expected;
 
     #[test]
     fn test_part_1() {
         let data = include_str!("../../data/day_01/test.txt");
         let puzzle = Puzzle::load(data);
-        assert_eq!(part_1(&puzzle), 11);
+        assert_eq!(part_1(&puzzle).to_string(), expected::<Day01>("test", 1));
     }
 
     #[test]
     fn test_part_2() {
         let data = include_str!("../../data/day_01/test.txt");
         let puzzle = Puzzle::load(data);
-        assert_eq!(part_2(&puzzle), 31);
+        assert_eq!(part_2(&puzzle).to_string(), expected::<Day01>("test", 2));
     }
 }
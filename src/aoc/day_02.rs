@@ -1,17 +1,21 @@
-struct Puzzle {
+use crate::aoc::parse::{parse_at, ParseError};
+use crate::aoc::solution::{Example, Solution};
+
+pub struct Puzzle {
     reports: Vec<Vec<u32>>
 }
 
 impl Puzzle {
-    fn parse(data: &str) -> Option<Self> {
+    fn parse(data: &str) -> Result<Self, ParseError> {
         let parse_nums = |s: &str| {
-            s.trim().split_ascii_whitespace().map(|s| s.parse()).collect()
+            s.trim().split_ascii_whitespace().map(|s| parse_at(data, s, "a level")).collect()
         };
         let reports: Result<_, _> = data.lines().map(parse_nums).collect();
-        let reports = reports.ok()?;
-        Some(Puzzle {reports})
+        let reports = reports?;
+        Ok(Puzzle {reports})
     }
 
+    #[cfg(test)]
     fn load(data: &str) -> Self {
         Self::parse(data).expect("valid input")
     }
@@ -46,16 +50,33 @@ fn part_2(puzzle: &Puzzle) -> usize {
     }).count()
 }
 
-pub(crate) fn solve() {
-    let data = include_str!("../../data/day_02/input.txt");
-    let puzzle = Puzzle::load(data);
-    println!("part 1: {}", part_1(&puzzle));
-    println!("part 2: {}", part_2(&puzzle));
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const EXAMPLES: &'static [Example] = &[Example::new("test", Some("2"), Some("4"))];
+
+    type Puzzle = Puzzle;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(data: &str) -> Result<Puzzle, ParseError> {
+        Puzzle::parse(data)
+    }
+
+    fn part_1(puzzle: &Puzzle) -> usize {
+        part_1(puzzle)
+    }
+
+    fn part_2(puzzle: &Puzzle) -> usize {
+        part_2(puzzle)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc::solution::expected;
 
     const DATA: &str = include_str!("../../data/day_02/test.txt");
 
@@ -69,12 +90,12 @@ mod tests {
     #[test]
     fn test_part_1() {
         let puzzle = Puzzle::load(DATA);
-        assert_eq!(part_1(&puzzle), 2);
+        assert_eq!(part_1(&puzzle).to_string(), expected::<Day02>("test", 1));
     }
 
     #[test]
     fn test_part_2() {
         let puzzle = Puzzle::load(DATA);
-        assert_eq!(part_2(&puzzle), 4);
+        assert_eq!(part_2(&puzzle).to_string(), expected::<Day02>("test", 2));
     }
 }
@@ -1,11 +1,21 @@
-struct Puzzle {
+use crate::aoc::parse::ParseError;
+use crate::aoc::solution::{Example, Solution};
+
+pub struct Puzzle {
     memory: String
 }
 
 impl Puzzle {
-    fn load(data: &str) -> Puzzle {
-        assert!(data.is_ascii());
-        Self {memory: data.to_string()}
+    fn parse(data: &str) -> Result<Self, ParseError> {
+        if let Some(i) = data.find(|c: char| !c.is_ascii()) {
+            return Err(ParseError::at(data, &data[i..], "an ascii character"));
+        }
+        Ok(Self {memory: data.to_string()})
+    }
+
+    #[cfg(test)]
+    fn load(data: &str) -> Self {
+        Self::parse(data).expect("valid input")
     }
 }
 
@@ -81,28 +91,46 @@ fn part_2(puzzle: &Puzzle) -> u64 {
     total
 }
 
-pub(crate) fn solve() {
-    let data = include_str!("../../data/day_03/input.txt");
-    let puzzle = Puzzle::load(data);
-    println!("part 1: {}", part_1(&puzzle));
-    println!("part 2: {}", part_2(&puzzle));
+const TEST_1: &str = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+const TEST_2: &str = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const EXAMPLES: &'static [Example] = &[Example::inline("test_1", TEST_1, Some("161"), None), Example::inline("test_2", TEST_2, None, Some("48"))];
+
+    type Puzzle = Puzzle;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(data: &str) -> Result<Puzzle, ParseError> {
+        Puzzle::parse(data)
+    }
+
+    fn part_1(puzzle: &Puzzle) -> u64 {
+        part_1(puzzle)
+    }
+
+    fn part_2(puzzle: &Puzzle) -> u64 {
+        part_2(puzzle)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc::solution::expected;
 
     #[test]
     fn test_part_1() {
-        let data = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
-        let puzzle = Puzzle::load(data);
-        assert_eq!(part_1(&puzzle), 161);
+        let puzzle = Puzzle::load(TEST_1);
+        assert_eq!(part_1(&puzzle).to_string(), expected::<Day03>("test_1", 1));
     }
 
     #[test]
     fn test_part_2() {
-        let data = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
-        let puzzle = Puzzle::load(data);
-        assert_eq!(part_2(&puzzle), 48);
+        let puzzle = Puzzle::load(TEST_2);
+        assert_eq!(part_2(&puzzle).to_string(), expected::<Day03>("test_2", 2));
     }
 }
\ No newline at end of file
@@ -1,182 +1,95 @@
-struct Puzzle {
-    letters: Vec<String>,
-    size: (usize, usize),
+use crate::aoc::grid::Grid;
+use crate::aoc::parse::ParseError;
+use crate::aoc::solution::{Example, Solution};
+
+pub struct Puzzle {
+    letters: Grid<char>
 }
 
 impl Puzzle {
-    fn load(data: &str) -> Self {
-        let letters: Vec<_> = data.lines().map(|s| s.to_string()).collect();
-        let size = (letters[0].len(), letters.len());
-        Puzzle {letters, size}
+    fn parse(data: &str) -> Result<Self, ParseError> {
+        let letters = Grid::parse(data, "")?;
+        Ok(Puzzle {letters})
     }
 
-    fn get(&self, r: usize, c: usize) -> char {
-        self.letters[r].as_bytes()[c] as char
+    #[cfg(test)]
+    fn load(data: &str) -> Self {
+        Self::parse(data).expect("valid input")
     }
 }
 
-struct PuzzleIterator<'a> {
-    data: &'a Puzzle,
-    position: usize,
-    direction: usize
+fn part_1(puzzle: &Puzzle) -> u32 {
+    let count = |s: String| s.match_indices("XMAS").count() + s.match_indices("SAMX").count();
+    let letters = &puzzle.letters;
+    let total: usize = letters.rows().map(|r| count(r.collect())).sum::<usize>()
+        + letters.columns().map(|c| count(c.collect())).sum::<usize>()
+        + letters.diagonals().map(|d| count(d.collect())).sum::<usize>()
+        + letters.anti_diagonals().map(|d| count(d.collect())).sum::<usize>();
+    total as u32
 }
 
-impl<'a> PuzzleIterator<'a> {
-    fn next_direction(&mut self) {
-        self.position = 0;
-        self.direction += 1;
-    }
-
-    fn next_position(&mut self) {
-        self.position += 1;
-        match self.direction {
-            0 => {
-                if self.position == self.data.size.1 {
-                    self.next_direction();
-                }
-            }
-            1 => {
-                if self.position == self.data.size.0 {
-                    self.next_direction();
-                }
-            }
-            2..=3 => {
-                if self.position == ((self.data.size.0 + self.data.size.1) - 1) {
-                    self.next_direction();
-                }
+fn part_2(puzzle: &Puzzle) -> u32 {
+    let (w, h) = puzzle.letters.size();
+    let is_mas = |a, b| matches!((a, b), ('M', 'S') | ('S', 'M'));
+    let mut total = 0;
+    for y in 0..h.saturating_sub(2) {
+        for x in 0..w.saturating_sub(2) {
+            let v = puzzle.letters.view(x, y, 3, 3);
+            if v.get((1, 1)) == 'A'
+                && is_mas(v.get((0, 0)), v.get((2, 2)))
+                && is_mas(v.get((2, 0)), v.get((0, 2))) {
+                total += 1;
             }
-            _ => ()
         }
     }
+    total
 }
 
-impl<'a> IntoIterator for &'a Puzzle {
-    type Item = String;
-    type IntoIter = PuzzleIterator<'a>;
+pub struct Day04;
 
-    fn into_iter(self) -> Self::IntoIter {
-        PuzzleIterator {data: self, position: 0, direction: 0}
-    }
-}
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const EXAMPLES: &'static [Example] = &[Example::new("test", Some("18"), Some("9"))];
 
-impl<'a> Iterator for PuzzleIterator<'a> {
-    type Item = String;
+    type Puzzle = Puzzle;
+    type Part1 = u32;
+    type Part2 = u32;
 
-    fn next(&mut self) -> Option<Self::Item> {
-        match self.direction {
-            0 => {
-                let r = self.data.letters[self.position].clone();
-                self.next_position();
-                Some(r)
-            }
-            1 => {
-                let mut r = String::new();
-                for s in &self.data.letters {
-                    r.push(s.as_bytes()[self.position] as char);
-                }
-                self.next_position();
-                Some(r)
-            }
-            2 => {
-                let mut r = String::new();
-                let br; let bc;
-                if self.position < self.data.size.1 {
-                    br = self.position;
-                    bc = 0;
-                } else {
-                    br = self.data.size.1 - 1;
-                    bc = self.position - self.data.size.1 + 1;
-                }
-                let s = std::cmp::min(br + 1, self.data.size.0 - bc);
-                for i in 0..s {
-                    r.push(self.data.letters[br - i].as_bytes()[bc + i] as char);
-                }
-                self.next_position();
-                Some(r)
-            }
-            3 => {
-                let mut r = String::new();
-                let (br, bc) = if self.position < self.data.size.1 {
-                    ((self.data.size.1 - 1) - self.position, 0)
-                } else {
-                    (0, self.position - self.data.size.1 + 1)
-                };
-                let s = std::cmp::min(self.data.size.1 - br, self.data.size.0 - bc);
-                for i in 0..s {
-                    r.push(self.data.letters[br + i].as_bytes()[bc + i] as char);
-                }
-                self.next_position();
-                Some(r)
-            }
-            _ => None
-        }
+    fn parse(data: &str) -> Result<Puzzle, ParseError> {
+        Puzzle::parse(data)
     }
-}
 
-fn part_1(puzzle: &Puzzle) -> u32 {
-    let mut total = 0;
-    for s in puzzle {
-        let nf = s.match_indices("XMAS").count();
-        let nb = s.match_indices("SAMX").count();
-        total += nf + nb;
+    fn part_1(puzzle: &Puzzle) -> u32 {
+        part_1(puzzle)
     }
-    total as u32
-}
 
-fn part_2(puzzle: &Puzzle) -> u32 {
-    let bc = 1;
-    let ec = puzzle.size.0 - 1;
-    let br = 1;
-    let er = puzzle.size.1 - 1;
-    let mut total = 0;
-
-    for r in br..er {
-        for c in bc..ec {
-            let mrmc = puzzle.get(r - 1, c - 1);
-            let mrpc = puzzle.get(r - 1, c + 1);
-            let prmc = puzzle.get(r + 1, c - 1);
-            let prpc = puzzle.get(r + 1, c + 1);
-            if puzzle.get(r, c) == 'A'
-                && ((mrmc == 'M' && prpc == 'S') || (mrmc == 'S' && prpc == 'M'))
-                && ((mrpc == 'M' && prmc == 'S') || (mrpc == 'S' && prmc == 'M')) {
-                total += 1;
-            }
-        }
+    fn part_2(puzzle: &Puzzle) -> u32 {
+        part_2(puzzle)
     }
-    total
-}
-
-pub(crate) fn solve() {
-    let data = include_str!("../../data/day_04/input.txt");
-    let puzzle = Puzzle::load(data);
-    println!("part 1: {}", part_1(&puzzle));
-    println!("part 2: {}", part_2(&puzzle));
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc::solution::expected;
 
     const DATA: &str = include_str!("../../data/day_04/test.txt");
 
     #[test]
     fn test_data() {
         let puzzle = Puzzle::load(DATA);
-        assert_eq!(puzzle.size.1, 10);
-        assert_eq!(puzzle.letters.len(), puzzle.size.1);
-        assert_eq!(puzzle.size.0, 10);
-        assert!(puzzle.letters.iter().all(|line| line.len() == puzzle.size.0));
+        assert_eq!(puzzle.letters.size(), (10, 10));
     }
 
     #[test]
     fn test_part_1() {
         let puzzle = Puzzle::load(DATA);
-        assert_eq!(part_1(&puzzle), 18);
+        assert_eq!(part_1(&puzzle).to_string(), expected::<Day04>("test", 1));
     }
 
     #[test]
     fn test_part_2() {
         let puzzle = Puzzle::load(DATA);
-        assert_eq!(part_2(&puzzle), 9);
+        assert_eq!(part_2(&puzzle).to_string(), expected::<Day04>("test", 2));
     }
 }
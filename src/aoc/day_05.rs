@@ -1,35 +1,38 @@
 use std::{cmp::Ordering, collections::HashMap};
+use crate::aoc::parse::{parse_at, ParseError};
+use crate::aoc::solution::{Example, Solution};
 
-struct Puzzle {
+pub struct Puzzle {
     rules: HashMap<u32, Vec<u32>>,
     updates: Vec<Vec<u32>>
 }
 
 impl Puzzle {
-    fn parse(data: &str) -> Option <Self> {
-        let data: Vec<_> = data.trim().lines().map(|s| s.trim()).collect();
+    fn parse(data: &str) -> Result<Self, ParseError> {
+        let lines: Vec<_> = data.trim().lines().map(|s| s.trim()).collect();
         let (order, updates) = {
-            let s = data.iter().position(|s| s.is_empty())?;
-            (data[..s].iter(), data[(s + 1)..].iter())
+            let s = lines.iter().position(|s| s.is_empty());
+            let s = s.ok_or_else(|| ParseError::after(data, data.trim_end(), "an empty line before the updates"))?;
+            (lines[..s].iter(), lines[(s + 1)..].iter())
         };
 
         let mut rules = HashMap::new();
         for update in order {
-            let (p1, p2) = update.split_once('|')?;
-            let (p1, p2) = (p1.parse().ok()?, p2.parse().ok()?);
+            let (p1, p2) = update.split_once('|').ok_or_else(|| ParseError::at(data, update, "'<page>|<page>'"))?;
+            let (p1, p2) = (parse_at(data, p1, "a page number")?, parse_at(data, p2, "a page number")?);
             rules.entry(p1).or_insert(Vec::new()).push(p2);
         }
 
         let parse_update = |ps: &&str| {
-            let ps: Option<Vec<_>> = ps.split(',').map(|p| p.parse().ok()).collect();
-            Some(ps?)
+            ps.split(',').map(|p| parse_at(data, p, "a page number")).collect::<Result<Vec<_>, _>>()
         };
-        let updates: Option<Vec<_>> = updates.map(parse_update).collect();
+        let updates: Result<Vec<_>, _> = updates.map(parse_update).collect();
         let updates = updates?;
 
-        Some(Puzzle {rules, updates})
+        Ok(Puzzle {rules, updates})
     }
 
+    #[cfg(test)]
     fn load(data: &str) -> Self {
         Self::parse(data).expect("valid input")
     }
@@ -64,16 +67,33 @@ fn part_2(data: &Puzzle) -> u32 {
         .sum()
 }
 
-pub(crate) fn solve() {
-    let data = include_str!("../../data/day_05/input.txt"); // 6951 / 4121
-    let puzzle = Puzzle::load(data);
-    println!("part 1: {}", part_1(&puzzle));
-    println!("part 2: {}", part_2(&puzzle));
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const EXAMPLES: &'static [Example] = &[Example::new("test", Some("143"), Some("123"))];
+
+    type Puzzle = Puzzle;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(data: &str) -> Result<Puzzle, ParseError> {
+        Puzzle::parse(data)
+    }
+
+    fn part_1(puzzle: &Puzzle) -> u32 {
+        part_1(puzzle)
+    }
+
+    fn part_2(puzzle: &Puzzle) -> u32 {
+        part_2(puzzle)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc::solution::expected;
 
     #[test]
     fn test_data() {
@@ -88,13 +108,13 @@ mod tests {
     fn test_part_1() {
         let data = include_str!("../../data/day_05/test.txt");
         let puzzle = Puzzle::load(data);
-        assert_eq!(part_1(&puzzle), 143);
+        assert_eq!(part_1(&puzzle).to_string(), expected::<Day05>("test", 1));
     }
 
     #[test]
     fn test_part_2() {
         let data = include_str!("../../data/day_05/test.txt");
         let puzzle = Puzzle::load(data);
-        assert_eq!(part_2(&puzzle), 123);
+        assert_eq!(part_2(&puzzle).to_string(), expected::<Day05>("test", 2));
     }
 }
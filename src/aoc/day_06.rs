@@ -1,4 +1,6 @@
-use crate::aoc::grid::Grid;
+use crate::aoc::grid::{Direction, Grid, Point};
+use crate::aoc::parse::ParseError;
+use crate::aoc::solution::{Example, Solution};
 
 #[derive(Eq, PartialEq)]
 enum Step {
@@ -8,50 +10,34 @@ enum Step {
     Out
 }
 
-type Position = (i32, i32, char);
+type Position = (Point, Direction);
 
 #[derive(Clone)]
-struct Puzzle {
+pub struct Puzzle {
     map: Grid<char>,
     position: Option<Position>
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
-        let map = data.trim().lines().map(|line| line.trim().chars().collect::<Vec<_>>()).collect::<Vec<_>>();
-        let map = Grid::from_vec(&map);
+    fn parse(data: &str) -> Result<Self, ParseError> {
+        let map = Grid::parse(data, "")?;
         let position = map.find_by(|c| "^v<>".contains(*c));
-        let position = position.and_then(|(x, y)| Some((x as i32, y as i32, map.get((x, y)))));
-        Self {map, position}
+        let position = position.and_then(|p| Some((Point::from(p), Direction::try_from(map.get(p)).ok()?)));
+        Ok(Self {map, position})
+    }
+
+    #[cfg(test)]
+    fn load(data: &str) -> Self {
+        Self::parse(data).expect("valid input")
     }
 
     fn next(&self) -> Option<Position> {
-        self.position.and_then(|(x, y, d)| {
-            let (nx, ny) = match d {
-                '^' => (x, y - 1),
-                '>' => (x + 1, y),
-                'v' => (x, y + 1),
-                '<' => (x - 1, y),
-                _ => panic!("invalid direction")
-            };
-            let (sx, sy) = self.map.size();
-            let (sx, sy) = (sx as i32, sy as i32);
-            if (nx >= 0) && (nx < sx) && (ny >= 0) && (ny < sy) {
-                let c = self.map.get((nx as usize, ny as usize));
-                if c == '#' {
-                    let nd = match d {
-                        '^' => '>',
-                        '>' => 'v',
-                        'v' => '<',
-                        '<' => '^',
-                        _ => panic!("invalid direction")
-                    };
-                    Some((x, y, nd))
-                } else {
-                    Some((nx, ny, d))
-                }
+        self.position.and_then(|(p, d)| {
+            let n = self.map.step(p, d)?;
+            if self.map.get(n.to_cell()) == '#' {
+                Some((p, d.turn_right()))
             } else {
-                None
+                Some((n, d))
             }
         })
     }
@@ -59,13 +45,13 @@ impl Puzzle {
     fn step(&mut self) -> Step {
         let position = self.next();
         match position {
-            Some((nx, ny, nd)) => {
+            Some((n, d)) => {
                 self.position = position;
-                let c = self.map.get((nx as usize, ny as usize));
+                let c = self.map.get(n.to_cell());
                 if c == '.' {
-                    self.map.set((nx as usize, ny as usize), nd);
+                    self.map.set(n.to_cell(), d.arrow());
                     Step::New
-                } else if c == nd {
+                } else if c == d.arrow() {
                     Step::Loop
                 } else {
                     Step::Visited
@@ -77,22 +63,6 @@ impl Puzzle {
             }
         }
     }
-
-    fn reset(&mut self, position: Option<Position>) {
-        let (w, h) = self.map.size();
-        for y in 0..h {
-            for x in 0..w {
-                let p = (x, y);
-                if self.map.get(p) != '#' {
-                    self.map.set(p, '.');
-                }
-            }
-        }
-        if let Some((x, y, d)) = position {
-            self.map.set((x as usize, y as usize), d);
-        }
-        self.position = position;
-    }
 }
 
 fn part_1(puzzle: &mut Puzzle) -> u32 {
@@ -107,9 +77,9 @@ fn part_2(puzzle: &mut Puzzle) -> u32 {
     let mut total = 0;
     while !puzzle.position.is_none() {
         let mut test = puzzle.clone();
-        if let Some((x, y, _)) = test.next() {
-            if test.map.get((x as usize, y as usize)) == '.' {
-                test.map.set((x as usize, y as usize), '#');
+        if let Some((p, _)) = test.next() {
+            if test.map.get(p.to_cell()) == '.' {
+                test.map.set(p.to_cell(), '#');
                 while !test.position.is_none() {
                     if test.step() == Step::Loop {
                         total += 1;
@@ -123,29 +93,45 @@ fn part_2(puzzle: &mut Puzzle) -> u32 {
     total
 }
 
-pub(crate) fn solve() {
-    let data = include_str!("../../data/day_06/input.txt");
-    let mut puzzle = Puzzle::new(data);
-    let start = puzzle.position;
-    println!("part 1: {}", part_1(&mut puzzle));
-    puzzle.reset(start);
-    println!("part 2: {}", part_2(&mut puzzle));
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const EXAMPLES: &'static [Example] = &[Example::new("test", Some("41"), Some("6"))];
+
+    type Puzzle = Puzzle;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(data: &str) -> Result<Puzzle, ParseError> {
+        Puzzle::parse(data)
+    }
+
+    fn part_1(puzzle: &Puzzle) -> u32 {
+        part_1(&mut puzzle.clone())
+    }
+
+    fn part_2(puzzle: &Puzzle) -> u32 {
+        part_2(&mut puzzle.clone())
+    }
 }
 
+#[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc::solution::expected;
 
     const DATA: &str = include_str!("../../data/day_06/test.txt");
 
     #[test]
     fn test_part_1() {
-        let mut puzzle = Puzzle::new(DATA);
-        assert_eq!(part_1(&mut puzzle), 41);
+        let mut puzzle = Puzzle::load(DATA);
+        assert_eq!(part_1(&mut puzzle).to_string(), expected::<Day06>("test", 1));
     }
 
     #[test]
     fn test_part_2() {
-        let mut puzzle = Puzzle::new(DATA);
-        assert_eq!(part_2(&mut puzzle), 6);
+        let mut puzzle = Puzzle::load(DATA);
+        assert_eq!(part_2(&mut puzzle).to_string(), expected::<Day06>("test", 2));
     }
 }
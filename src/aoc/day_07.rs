@@ -1,3 +1,6 @@
+use crate::aoc::parse::{parse_at, ParseError};
+use crate::aoc::solution::{Example, Solution};
+
 #[derive(Clone, Copy)]
 enum Op {
     Add,
@@ -52,24 +55,25 @@ impl Equation {
     }
 }
 
-struct Puzzle {
+pub struct Puzzle {
     equations: Vec<Equation>
 }
 
 impl Puzzle {
-    fn parse(data: &str) -> Option<Self> {
-        let parse_equation = |s: &str| {
-            let (result, numbers) = s.trim().split_once(": ")?;
-            let result = result.parse().ok()?;
-            let numbers: Option<Vec<_>> = numbers.split(' ').map(|v| v.parse().ok()).collect();
+    fn parse(data: &str) -> Result<Self, ParseError> {
+        let parse_equation = |s: &str| -> Result<_, ParseError> {
+            let (result, numbers) = s.trim().split_once(": ").ok_or_else(|| ParseError::at(data, s, "'<value>: <numbers>'"))?;
+            let result = parse_at(data, result, "a test value")?;
+            let numbers: Result<Vec<_>, _> = numbers.split(' ').map(|v| parse_at(data, v, "a number")).collect();
             let numbers = numbers?;
-            Some(Equation {result, numbers})
+            Ok(Equation {result, numbers})
         };
-        let equations: Option<Vec<_>> = data.trim().lines().map(parse_equation).collect();
+        let equations: Result<Vec<_>, _> = data.trim().lines().map(parse_equation).collect();
         let equations = equations?;
-        Some(Puzzle {equations})
+        Ok(Puzzle {equations})
     }
 
+    #[cfg(test)]
     fn load(data: &str) -> Self {
         Self::parse(data).expect("valid input")
     }
@@ -89,27 +93,45 @@ fn part_2(puzzle: &Puzzle) -> u64 {
         .sum()
 }
 
-pub(crate) fn solve() {
-    let data = include_str!("../../data/day_07/input.txt");
-    let puzzle = Puzzle::load(data);
-    println!("part 1: {}", part_1(&puzzle));
-    println!("part 2: {}", part_2(&puzzle));
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const EXAMPLES: &'static [Example] = &[Example::new("test", Some("3749"), Some("11387"))];
+
+    type Puzzle = Puzzle;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(data: &str) -> Result<Puzzle, ParseError> {
+        Puzzle::parse(data)
+    }
+
+    fn part_1(puzzle: &Puzzle) -> u64 {
+        part_1(puzzle)
+    }
+
+    fn part_2(puzzle: &Puzzle) -> u64 {
+        part_2(puzzle)
+    }
 }
 
+#[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc::solution::expected;
 
     const DATA: &str = include_str!("../../data/day_07/test.txt");
 
     #[test]
     fn test_part_1() {
         let data = Puzzle::load(DATA);
-        assert_eq!(part_1(&data), 3749);
+        assert_eq!(part_1(&data).to_string(), expected::<Day07>("test", 1));
     }
 
     #[test]
     fn test_part_2() {
         let data = Puzzle::load(DATA);
-        assert_eq!(part_2(&data), 11387);
+        assert_eq!(part_2(&data).to_string(), expected::<Day07>("test", 2));
     }
 }
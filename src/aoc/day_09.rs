@@ -1,23 +1,28 @@
-struct Puzzle {
+use crate::aoc::parse::ParseError;
+use crate::aoc::solution::{Example, Solution};
+
+pub struct Puzzle {
     fs: Vec<u32>,
     files: Vec<(usize, usize)>,
     spaces: Vec<(usize, usize)>
 }
 
 impl Puzzle {
-    fn parse(data: &str) -> Option<Self> {
+    fn parse(data: &str) -> Result<Self, ParseError> {
         let mut fs = Vec::new();
         let mut files = Vec::new();
         let mut spaces = Vec::new();
-        for (i, c) in data.trim().chars().enumerate() {
-            let n = c.to_digit(10)?;
+        let disk = data.trim();
+        for (i, c) in disk.char_indices() {
+            let n = c.to_digit(10).ok_or_else(|| ParseError::at(data, &disk[i..], "a digit"))?;
             let id = if (i & 1) == 0 {(i / 2) as u32} else {u32::MAX};
             (if id != u32::MAX {&mut files} else {&mut spaces}).push((fs.len(), n as usize));
             fs.extend(std::iter::repeat(id).take(n as usize));
         }
-        Some(Self {fs, files, spaces})
+        Ok(Self {fs, files, spaces})
     }
 
+    #[cfg(test)]
     fn load(data: &str) -> Self {
         Self::parse(data).expect("valid input")
     }
@@ -69,16 +74,33 @@ fn solve_part_2(puzzle: &Puzzle) -> u64 {
     checksum(&fs)
 }
 
-pub(crate) fn solve() {
-    let data = include_str!("../../data/day_09/input.txt");
-    let puzzle = Puzzle::load(data);
-    println!("part 1: {}", solve_part_1(&puzzle));
-    println!("part 2: {}", solve_part_2(&puzzle));
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const EXAMPLES: &'static [Example] = &[Example::new("test", Some("1928"), Some("2858"))];
+
+    type Puzzle = Puzzle;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(data: &str) -> Result<Puzzle, ParseError> {
+        Puzzle::parse(data)
+    }
+
+    fn part_1(puzzle: &Puzzle) -> u64 {
+        solve_part_1(puzzle)
+    }
+
+    fn part_2(puzzle: &Puzzle) -> u64 {
+        solve_part_2(puzzle)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc::solution::expected;
 
     #[test]
     fn test_data() {
@@ -92,13 +114,13 @@ mod tests {
     fn test_part_1() {
         let data = include_str!("../../data/day_09/test.txt");
         let puzzle = Puzzle::load(data);
-        assert_eq!(solve_part_1(&puzzle), 1928);
+        assert_eq!(solve_part_1(&puzzle).to_string(), expected::<Day09>("test", 1));
     }
 
     #[test]
     fn test_part_2() {
         let data = include_str!("../../data/day_09/test.txt");
         let puzzle = Puzzle::load(data);
-        assert_eq!(solve_part_2(&puzzle), 2858);
+        assert_eq!(solve_part_2(&puzzle).to_string(), expected::<Day09>("test", 2));
     }
 }
@@ -1,58 +1,44 @@
 use std::collections::HashSet;
-use crate::aoc::grid::Grid;
+use crate::aoc::grid::{Grid, Point};
+use crate::aoc::parse::ParseError;
+use crate::aoc::solution::{Example, Solution};
 
-struct Puzzle {
+pub struct Puzzle {
     map:Grid<u8>
 }
 
 impl Puzzle {
-    fn parse(data: &str) -> Option<Self> {
+    fn parse(data: &str) -> Result<Self, ParseError> {
         let map= Grid::parse(data, "")?;
-        Some(Self {map})
+        Ok(Self {map})
     }
 
+    #[cfg(test)]
     fn load(data: &str) -> Self {
         Self::parse(data).expect("valid input")
     }
 
-    fn score(&self, x: usize, y: usize) -> u32 {
-        if self.map.get((x, y)) != 0 {return 0;}
-        let (w, h) = self.map.size();
-        let (w, h) = (w as i32, h as i32);
+    fn score(&self, p: Point) -> u32 {
+        if self.map.get(p.to_cell()) != 0 {return 0;}
         let mut ps = HashSet::new();
-        ps.insert((x as i32, y as i32));
+        ps.insert(p);
         for z in 1..=9 {
             let mut nps = HashSet::new();
-            for (x, y) in &ps {
-                let (x, y) = (*x, *y);
-                for (dx, dy) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
-                    let (nx, ny) = (x + dx, y + dy);
-                    if (nx >= 0) && (nx < w as i32) && (ny >= 0) && (ny < h as i32) && (self.map.get((nx as usize, ny as usize)) == z) {
-                        nps.insert((nx, ny));
-                    }
-                }
+            for p in &ps {
+                nps.extend(self.map.neighbors4(*p).filter(|&(_, c)| c == z).map(|(n, _)| n));
             }
             ps = nps;
         }
         ps.len() as u32
     }
 
-    fn rating(&self, x: usize, y: usize) -> u32 {
-        if self.map.get((x, y)) != 9 {return 0;}
-        let (w, h) = self.map.size();
-        let (w, h) = (w as i32, h as i32);
-        let mut ps = Vec::new();
-        ps.push((x as i32, y as i32));
+    fn rating(&self, p: Point) -> u32 {
+        if self.map.get(p.to_cell()) != 9 {return 0;}
+        let mut ps = vec![p];
         for z in (0..=8).rev() {
             let mut nps = Vec::new();
-            for (x, y) in &ps {
-                let (x, y) = (*x, *y);
-                for (dx, dy) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
-                    let (nx, ny) = (x + dx, y + dy);
-                    if (nx >= 0) && (nx < w as i32) && (ny >= 0) && (ny < h as i32) && (self.map.get((nx as usize, ny as usize)) == z) {
-                        nps.push((nx, ny));
-                    }
-                }
+            for p in &ps {
+                nps.extend(self.map.neighbors4(*p).filter(|&(_, c)| c == z).map(|(n, _)| n));
             }
             ps = nps;
         }
@@ -61,49 +47,52 @@ impl Puzzle {
 }
 
 fn part_1(puzzle: &Puzzle) -> u32 {
-    let (w, h) = puzzle.map.size();
-    let mut total = 0;
-    for y in 0..h {
-        for x in 0..w {
-            total += puzzle.score(x, y);
-        }
-    }
-    return total;
+    puzzle.map.cells().map(|(p, _)| puzzle.score(Point::from(p))).sum()
 }
 
 fn part_2(puzzle: &Puzzle) -> u32 {
-    let (w, h) = puzzle.map.size();
-    let mut total = 0;
-    for y in 0..h {
-        for x in 0..w {
-            total += puzzle.rating(x, y);
-        }
-    }
-    return total;
+    puzzle.map.cells().map(|(p, _)| puzzle.rating(Point::from(p))).sum()
 }
 
-pub(crate) fn solve() {
-    let data = include_str!("../../data/day_10/input.txt");
-    let data = Puzzle::load(data);
-    println!("part 1: {}", part_1(&data));
-    println!("part 2: {}", part_2(&data));
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const EXAMPLES: &'static [Example] = &[Example::new("test", Some("36"), Some("81"))];
+
+    type Puzzle = Puzzle;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(data: &str) -> Result<Puzzle, ParseError> {
+        Puzzle::parse(data)
+    }
+
+    fn part_1(puzzle: &Puzzle) -> u32 {
+        part_1(puzzle)
+    }
+
+    fn part_2(puzzle: &Puzzle) -> u32 {
+        part_2(puzzle)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc::solution::expected;
 
     #[test]
     fn test_part_1() {
         let data = include_str!("../../data/day_10/test.txt");
         let puzzle = Puzzle::load(data);
-        assert_eq!(part_1(&puzzle), 36);
+        assert_eq!(part_1(&puzzle).to_string(), expected::<Day10>("test", 1));
     }
 
     #[test]
     fn test_part_2() {
         let data = include_str!("../../data/day_10/test.txt");
         let puzzle = Puzzle::load(data);
-        assert_eq!(part_2(&puzzle), 81);
+        assert_eq!(part_2(&puzzle).to_string(), expected::<Day10>("test", 2));
     }
 }
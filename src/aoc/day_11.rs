@@ -1,17 +1,20 @@
 use std::collections::HashMap;
+use crate::aoc::parse::{parse_at, ParseError};
+use crate::aoc::solution::{Example, Solution};
 
-struct Puzzle {
+pub struct Puzzle {
     stones: Vec<u64>
 }
 
 impl Puzzle {
-    fn parse(data: &str) -> Option <Self> {
-        let stones: Option<Vec<_>> = data.trim().split(' ').map(|s| s.parse().ok()).collect();
+    fn parse(data: &str) -> Result<Self, ParseError> {
+        let stones: Result<Vec<_>, _> = data.trim().split(' ').map(|s| parse_at(data, s, "a number")).collect();
         let stones = stones?;
-        Some(Self {stones})
+        Ok(Self {stones})
     }
 
     
+    #[cfg(test)]
     fn load(data: &str) -> Self {
         Self::parse(data).expect("valid input")
     }
@@ -48,16 +51,33 @@ fn part_2(puzzle: &Puzzle) -> u64 {
     puzzle.blink(75)
 }
 
-pub(crate) fn solve() {
-    let data = include_str!("../../data/day_11/input.txt");
-    let puzzle = Puzzle::load(data);
-    println!("part 1: {}", part_1(&puzzle));
-    println!("part 2: {}", part_2(&puzzle));
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const EXAMPLES: &'static [Example] = &[Example::new("test", Some("55312"), None)];
+
+    type Puzzle = Puzzle;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(data: &str) -> Result<Puzzle, ParseError> {
+        Puzzle::parse(data)
+    }
+
+    fn part_1(puzzle: &Puzzle) -> u64 {
+        part_1(puzzle)
+    }
+
+    fn part_2(puzzle: &Puzzle) -> u64 {
+        part_2(puzzle)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc::solution::expected;
 
     #[test]
     fn test_data() {
@@ -70,7 +90,7 @@ mod tests {
     fn test_part_1() {
         let data = include_str!("../../data/day_11/test.txt");
         let puzzle = Puzzle::load(data);
-        assert_eq!(part_1(&puzzle), 55312);
+        assert_eq!(part_1(&puzzle).to_string(), expected::<Day11>("test", 1));
     }
 
     #[test]
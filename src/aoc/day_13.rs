@@ -1,30 +1,38 @@
+use crate::aoc::parse::{parse_at, ParseError};
+use crate::aoc::solution::{Example, Solution};
+
 #[derive(Debug)]
 struct Game {
     button_a: (i64, i64),
     button_b: (i64, i64),
     prize: (i64, i64)
 }
-struct Puzzle {
+pub struct Puzzle {
     games: Vec<Game>
 }
 
 impl Puzzle {
-    fn parse(data: &str) -> Option<Self> {
-        let parse_game = |s: &str| {
+    fn parse(data: &str) -> Result<Self, ParseError> {
+        let data = data.replace("\r", "");
+        let parse_game = |s: &str| -> Result<_, ParseError> {
             let mut abp = s.lines();
-            let (a_x, a_y) = abp.next()?.trim().split_once(": ")?.1.split_once(", ")?;
-            let (b_x, b_y) = abp.next()?.trim().split_once(": ")?.1.split_once(", ")?;
-            let (p_x, p_y) = abp.next()?.trim().split_once(": ")?.1.split_once(", ")?;
-            let button_a = (a_x.trim_start_matches("X+").parse().ok()?, a_y.trim_start_matches("Y+").parse().ok()?);
-            let button_b = (b_x.trim_start_matches("X+").parse().ok()?, b_y.trim_start_matches("Y+").parse().ok()?);
-            let prize = (p_x.trim_start_matches("X=").parse().ok()?, p_y.trim_start_matches("Y=").parse().ok()?);
-            Some(Game {button_a, button_b, prize})
+            let mut parse_xy = |expected: &str, x_prefix: &str, y_prefix: &str| -> Result<(i64, i64), ParseError> {
+                let line = abp.next().ok_or_else(|| ParseError::after(&data, s, expected))?.trim();
+                let xy = line.split_once(": ").and_then(|(_, xy)| xy.split_once(", "));
+                let (x, y) = xy.ok_or_else(|| ParseError::at(&data, line, expected))?;
+                Ok((parse_at(&data, x.trim_start_matches(x_prefix), "a number")?, parse_at(&data, y.trim_start_matches(y_prefix), "a number")?))
+            };
+            let button_a = parse_xy("button A", "X+", "Y+")?;
+            let button_b = parse_xy("button B", "X+", "Y+")?;
+            let prize = parse_xy("a prize", "X=", "Y=")?;
+            Ok(Game {button_a, button_b, prize})
         };
-        let games: Option<_> = data.trim().replace("\r", "").split("\n\n").map(parse_game).collect();
+        let games: Result<_, _> = data.trim().split("\n\n").map(parse_game).collect();
         let games = games?;
-        Some(Puzzle {games})
+        Ok(Puzzle {games})
     }
 
+    #[cfg(test)]
     fn load(data: &str) -> Self {
         Self::parse(data).expect("valid input")
     }
@@ -66,16 +74,33 @@ fn part_2(puzzle: &Puzzle) -> i64 {
     }).sum()
 }
 
-pub(crate) fn solve() {
-    let data = include_str!("../../data/day_13/input.txt");
-    let puzzle = Puzzle::load(data);
-    println!("part 1: {}", part_1(&puzzle));
-    println!("part 2: {}", part_2(&puzzle));
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const EXAMPLES: &'static [Example] = &[Example::new("test", Some("480"), None)];
+
+    type Puzzle = Puzzle;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(data: &str) -> Result<Puzzle, ParseError> {
+        Puzzle::parse(data)
+    }
+
+    fn part_1(puzzle: &Puzzle) -> i64 {
+        part_1(puzzle)
+    }
+
+    fn part_2(puzzle: &Puzzle) -> i64 {
+        part_2(puzzle)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc::solution::expected;
 
     #[test]
     fn test_data() {
@@ -88,6 +113,6 @@ mod tests {
     fn test_part_1() {
         let data = include_str!("../../data/day_13/test.txt");
         let puzzle = Puzzle::load(data);
-        assert_eq!(part_1(&puzzle), 480);
+        assert_eq!(part_1(&puzzle).to_string(), expected::<Day13>("test", 1));
     }
 }
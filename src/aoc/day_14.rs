@@ -1,4 +1,8 @@
+use crate::aoc::cancel;
+#[cfg(test)]
 use crate::aoc::grid::Grid;
+use crate::aoc::parse::{parse_at, ParseError};
+use crate::aoc::solution::Solution;
 
 struct Robot {
     px: i32,
@@ -6,25 +10,26 @@ struct Robot {
     vx: i32,
     vy: i32
 }
-struct Puzzle {
+pub struct Puzzle {
     robots: Vec<Robot>
 }
 
 impl Puzzle {
-    fn parse(data: &str) -> Option<Self> {
-        let parse_robot = |s: &str| {
-            let (p, v) = s.split_once(" ")?;
-            let (px, py) = p.trim_start_matches("p=").split_once(",")?;
-            let (vx, vy) = v.trim_start_matches("v=").split_once(",")?;
-            let (px, py) = (px.parse().ok()?, py.parse().ok()?);
-            let (vx, vy) = (vx.parse().ok()?, vy.parse().ok()?);
-            Some(Robot {px, py, vx, vy})
+    fn parse(data: &str) -> Result<Self, ParseError> {
+        let parse_robot = |s: &str| -> Result<_, ParseError> {
+            let (p, v) = s.split_once(" ").ok_or_else(|| ParseError::at(data, s, "'p=<x>,<y> v=<x>,<y>'"))?;
+            let (px, py) = p.trim_start_matches("p=").split_once(",").ok_or_else(|| ParseError::at(data, p, "'p=<x>,<y>'"))?;
+            let (vx, vy) = v.trim_start_matches("v=").split_once(",").ok_or_else(|| ParseError::at(data, v, "'v=<x>,<y>'"))?;
+            let (px, py) = (parse_at(data, px, "a number")?, parse_at(data, py, "a number")?);
+            let (vx, vy) = (parse_at(data, vx, "a number")?, parse_at(data, vy, "a number")?);
+            Ok(Robot {px, py, vx, vy})
         };
-        let robots: Option<_> = data.trim().lines().map(parse_robot).collect();
+        let robots: Result<_, _> = data.trim().lines().map(parse_robot).collect();
         let robots = robots?;
-        Some(Puzzle {robots})
+        Ok(Puzzle {robots})
     }
 
+    #[cfg(test)]
     fn load(data: &str) -> Self {
         Self::parse(data).expect("valid input")
     }
@@ -58,7 +63,9 @@ fn part_1(puzzle: &Puzzle) -> u32 {
     quadrants(puzzle, (101, 103), 100)
 }
 
-fn print_robots(robots: &Vec<Robot>, size: (usize, usize), time: u32) {
+// the picture of the robots at a time, to look at part 2 while debugging
+#[cfg(test)]
+fn draw_robots(robots: &[Robot], size: (usize, usize), time: u32) -> Grid<char> {
     let (w, h) = size;
     let (w, h) = (w as i32, h as i32);
     let mut map = Grid::new(size, ' ');
@@ -70,7 +77,7 @@ fn print_robots(robots: &Vec<Robot>, size: (usize, usize), time: u32) {
     }) {
         map.set(p, '#');
     }
-    println!("{map}");
+    map
 }
 
 use std::collections::HashMap;
@@ -82,6 +89,7 @@ fn part_2(puzzle: &Puzzle, size: (usize, usize)) -> u32 {
     // assume that at least half of the robots are in the middle of the map
     let (w4, h4) = (w / 4, h / 4);
     for t in 0.. {
+        cancel::check();
         if puzzle.robots.iter().map(|r| {
             let mut x = (r.px + r.vx * t) % w;
             let mut y = (r.py + r.vy * t) % h;
@@ -91,7 +99,6 @@ fn part_2(puzzle: &Puzzle, size: (usize, usize)) -> u32 {
         }).filter(|(x, y)| {
             (*x > w4) && (*x < (w - w4)) && (*y > h4) && (*y < (h - h4))
         }).count() > (n / 2) {
-            print_robots(&puzzle.robots, size, t as u32);
             return t as u32;
         }
     }
@@ -120,11 +127,26 @@ fn part_2(puzzle: &Puzzle, size: (usize, usize)) -> u32 {
     0
 }
 
-pub(crate) fn solve() {
-    let data = include_str!("../../data/day_14/input.txt");
-    let puzzle = Puzzle::load(data);
-    println!("part 1: {}", part_1(&puzzle));
-    println!("part 2: {}", part_2(&puzzle, (101, 103)));
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Puzzle = Puzzle;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(data: &str) -> Result<Puzzle, ParseError> {
+        Puzzle::parse(data)
+    }
+
+    fn part_1(puzzle: &Puzzle) -> u32 {
+        part_1(puzzle)
+    }
+
+    fn part_2(puzzle: &Puzzle) -> u32 {
+        part_2(puzzle, (101, 103))
+    }
 }
 
 #[cfg(test)]
@@ -144,4 +166,22 @@ mod tests {
         let puzzle = Puzzle::load(data);
         assert_eq!(quadrants(&puzzle, (11, 7), 100), 12);
     }
+
+    #[test]
+    fn test_draw_robots() {
+        let data = include_str!("../../data/day_14/test.txt");
+        let puzzle = Puzzle::load(data);
+        let map = draw_robots(&puzzle.robots, (11, 7), 100);
+        let picture = "
+        ......#..#.
+        ...........
+        #..........
+        .##........
+        .....#.....
+        ...##......
+        .#....#....
+        ";
+        let picture: Vec<_> = picture.trim().lines().map(|r| r.trim().replace('.', " ")).collect();
+        assert_eq!(map.to_string(), picture.join("\n"));
+    }
 }
@@ -1,19 +1,25 @@
-use crate::aoc::grid::Grid;
+use crate::aoc::grid::{Direction, Grid, Point};
+use crate::aoc::parse::ParseError;
+use crate::aoc::solution::{Example, Solution};
 
-struct Puzzle {
+pub struct Puzzle {
     map: Grid<char>,
-    moves: Vec<char>
+    moves: Vec<Direction>
 }
 
 impl Puzzle {
-    fn parse(data: &str) -> Option<Self> {
+    fn parse(data: &str) -> Result<Self, ParseError> {
         let data = data.trim().replace("\r", "");
-        let (map, moves) = data.split_once("\n\n")?;
+        let (map, moves) = data.split_once("\n\n").ok_or_else(|| ParseError::after(&data, &data, "an empty line before the moves"))?;
         let map = Grid::parse(map, "")?;
-        let moves = moves.lines().map(|s| s.trim()).collect::<String>().chars().collect();
-        Some(Puzzle {map, moves})
+        if let Some(i) = moves.find(|c: char| !"^v<>".contains(c) && !c.is_whitespace()) {
+            return Err(ParseError::at(&data, &moves[i..], "a move"));
+        }
+        let moves = moves.chars().filter_map(|c| Direction::try_from(c).ok()).collect();
+        Ok(Puzzle {map, moves})
     }
 
+    #[cfg(test)]
     fn load(data: &str) -> Self {
         Self::parse(data).expect("valid input")
     }
@@ -22,32 +28,22 @@ impl Puzzle {
 fn part_1(puzzle: &Puzzle) -> u32 {
     let mut map = puzzle.map.clone();
 
-    let (x, y) = puzzle.map.find('@').expect("robot");
-    let (mut x, mut y) = (x as i32, y as i32);
-
-    for m in &puzzle.moves {
-        let (dx, dy) = match m {
-            '<' => (-1, 0),
-            '>' => (1, 0),
-            '^' => (0, -1),
-            'v' => (0, 1),
-            _ => panic!("invalid move")
-        };
-        let (mut nx, mut ny) = (x + dx, y + dy);
-        while map.get((nx as usize, ny as usize)) == 'O' {
-            nx += dx;
-            ny += dy;
+    let mut p = Point::from(puzzle.map.find('@').expect("robot"));
+
+    for d in &puzzle.moves {
+        let v = d.vector();
+        let mut n = p + v;
+        while map.try_get(n) == Some('O') {
+            n += v;
         }
-        if map.get((nx as usize, ny as usize)) == '.' {
-            let d = (nx - x).abs() + (ny - y).abs();
-            for _ in 0..d {
-                let c = map.get(((nx - dx) as usize, (ny - dy) as usize));
-                map.set((nx as usize, ny as usize), c);
-                nx -= dx;
-                ny -= dy;
+        if map.try_get(n) == Some('.') {
+            while n != p {
+                let c = map.get((n - v).to_cell());
+                map.set(n.to_cell(), c);
+                n -= v;
             }
-            map.set((x as usize, y as usize), '.');
-            x += dx; y += dy;
+            map.set(p.to_cell(), '.');
+            p += v;
         }
     }
     let mut total = 0;
@@ -68,28 +64,45 @@ fn part_2(data: &Puzzle) -> u32 {
     todo!("part 2");
 }
 
-pub(crate) fn solve() {
-    let data = include_str!("../../data/day_15/input.txt");
-    let puzzle = Puzzle::load(data);
-    println!("part 1: {}", part_1(&puzzle));
-    println!("part 2: {}", part_2(&puzzle));
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const EXAMPLES: &'static [Example] = &[Example::new("test_1", Some("2028"), None), Example::new("test_2", Some("10092"), None)];
+
+    type Puzzle = Puzzle;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(data: &str) -> Result<Puzzle, ParseError> {
+        Puzzle::parse(data)
+    }
+
+    fn part_1(puzzle: &Puzzle) -> u32 {
+        part_1(puzzle)
+    }
+
+    fn part_2(puzzle: &Puzzle) -> u32 {
+        part_2(puzzle)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc::solution::expected;
 
     #[test]
     fn test_part_1_1() {
         let data = include_str!("../../data/day_15/test_1.txt");
         let puzzle = Puzzle::load(data);
-        assert_eq!(part_1(&puzzle), 2028);
+        assert_eq!(part_1(&puzzle).to_string(), expected::<Day15>("test_1", 1));
     }
 
     #[test]
     fn test_part_1_2() {
         let data = include_str!("../../data/day_15/test_2.txt");
         let puzzle = Puzzle::load(data);
-        assert_eq!(part_1(&puzzle), 10092);
+        assert_eq!(part_1(&puzzle).to_string(), expected::<Day15>("test_2", 1));
     }
 }
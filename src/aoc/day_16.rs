@@ -1,143 +1,95 @@
-// use std::collections::VecDeque;
 use std::collections::HashSet;
+use crate::aoc::grid::{Direction, Grid, Point};
+use crate::aoc::parse::ParseError;
+use crate::aoc::search;
+use crate::aoc::solution::{Example, Solution};
 
-struct Data {
-    grid: Vec<Vec<char>>
+pub struct Data {
+    grid: Grid<char>
 }
 
 impl Data {
-    fn parse(data: &str) -> Self {
-        let grid = data.trim().lines().map(|s| {
-            s.trim().chars().collect()
-        }).collect();
-        Data {grid}
+    fn parse(data: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse(data, "")?;
+        Ok(Data {grid})
     }
 
-    fn get(&self, p: &Position) -> char {
-        self.grid[p.y as usize][p.x as usize]
+    #[cfg(test)]
+    fn load(data: &str) -> Self {
+        Self::parse(data).expect("valid input")
     }
 
-    // fn get_mut(&mut self, x: i32, y: i32) -> &mut char {
-    //     &mut self.grid[y as usize][x as usize]
-    // }
-
-    fn size(&self) -> (i32, i32) {
-        (self.grid[0].len() as i32, self.grid.len() as i32)
+    fn get(&self, p: &Point) -> char {
+        self.grid.get(p.to_cell())
     }
 
-    fn find(&self, c: char) -> Option<Position> {
-        let (w, h) = self.size();
-        for y in 1..(h - 1) {
-            for x in 1..(w - 1) {
-                let p = Position {x, y};
-                if self.get(&p) == c {
-                    return 
-                    Some(p);
-                }
-            }
-        }
-        None
+    fn find(&self, c: char) -> Option<Point> {
+        self.grid.find(c).map(Point::from)
     }
 }
 
-fn get_next_position(p: &Position, d: char) -> Position {
-    let (dx, dy) = match d {
-        '<' => (-1, 0),
-        '>' => (1, 0),
-        '^' => (0, -1),
-        'v' => (0, 1),
-        _ => panic!("invalid move")
-    };
-    Position {x: p.x + dx, y: p.y + dy}
-}
+type Tile = (Point, Direction);
 
-impl std::fmt::Display for Data {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        let mut nl = false;
-        for r in &self.grid {
-            if nl {write!(f, "\n")?;}
-            for c in r {
-                write!(f, "{}", c)?;
-            }
-            nl = true;
-        }
-        Ok(())
-    }
+// a reindeer moves forward for 1 point or turns for 1000
+fn get_moves(data: &Data, (p, d): Tile) -> impl Iterator<Item = (Tile, u64)> {
+    let forward = p.step(d);
+    let forward = (data.get(&forward) != '#').then_some(((forward, d), 1));
+    [forward, Some(((p, d.turn_left()), 1000)), Some(((p, d.turn_right()), 1000))].into_iter().flatten()
 }
 
-#[derive(Debug)]
-struct Position {
-    x: i32,
-    y: i32
-}
-#[derive(Debug)]
-struct Tile {
-    p: Position,
-    d: char,
-    t: u32
-}
-
-fn solve_maze(data: &Data) -> Option<u32> {
-    let mut ps = Vec::new();
-    let mut vs = HashSet::new();
-    ps.push(Tile {p: data.find('S').expect("start"), d: '>', t: 0});
-    while !ps.is_empty() {
-        let Tile {p, d, t} = ps.pop().unwrap();
-        if data.get(&p) == 'E' {
-            return Some(t);
-        }
-        vs.insert((p.x, p.y, d));
-        let np = get_next_position(&p, d);
-        if !vs.contains(&(np.x, np.y, d)) && data.get(&np) != '#' {
-            ps.push(Tile {p: np, d, t: t + 1});
-        }
-        let nds = if (d == '<') || (d == '>') {('^', 'v')} else {('<', '>')};
-        let np = get_next_position(&p, nds.0);
-        if !vs.contains(&(np.x, np.y, d)) && data.get(&np) != '#' {
-            ps.push(Tile {p: np, d: nds.0, t: t + 1001});
-        }
-        let np = get_next_position(&p, nds.1);
-        if !vs.contains(&(np.x, np.y, d)) && data.get(&np) != '#' {
-            ps.push(Tile {p: np, d: nds.1, t: t + 1001});
-        }
-        ps.sort_by(|a, b| b.t.cmp(&a.t));
-    }
-    None
+fn get_start(data: &Data) -> Tile {
+    (data.find('S').expect("start"), Direction::East)
 }
 
 fn part_1(data: &Data) -> u32 {
-    solve_maze(data).unwrap()
+    let search = search::dijkstra([get_start(data)], |t| get_moves(data, t), |(p, _)| data.get(&p) == 'E');
+    search.distance().expect("a path") as u32
 }
 
 fn part_2(data: &Data) -> u32 {
-    todo!("part 2");
+    let paths = search::dijkstra_all([get_start(data)], |t| get_moves(data, t), |(p, _)| data.get(&p) == 'E');
+    let tiles: HashSet<_> = paths.states().into_iter().map(|(p, _)| p).collect();
+    tiles.len() as u32
 }
 
-pub(crate) fn solve() {
-    let data = include_str!("../../data/day_16/test_1.txt");
-    let data = Data::parse(data);
-    println!("{data}");
+pub struct Day16;
 
-    let data = include_str!("../../data/day_16/input.txt");
-    let data = Data::parse(data);
-    println!("part 1: {}", part_1(&data));
-    println!("part 2: {}", part_2(&data));
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    const EXAMPLES: &'static [Example] = &[Example::new("test_1", Some("7036"), Some("45")), Example::new("test_2", Some("11048"), Some("64"))];
+
+    type Puzzle = Data;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(data: &str) -> Result<Data, ParseError> {
+        Data::parse(data)
+    }
+
+    fn part_1(puzzle: &Data) -> u32 {
+        part_1(puzzle)
+    }
+
+    fn part_2(puzzle: &Data) -> u32 {
+        part_2(puzzle)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc::solution::expected;
 
     #[test]
     fn test_data() {
         let data = include_str!("../../data/day_16/test_1.txt");
-        let data = Data::parse(data);
-        let size = data.size();
+        let data = Data::load(data);
+        let size = data.grid.size();
         assert!((size.0 == 15) && (size.1 == 15));
 
         let data = include_str!("../../data/day_16/test_2.txt");
-        let data = Data::parse(data);
-        let size = data.size();
+        let data = Data::load(data);
+        let size = data.grid.size();
         assert!((size.0 == 17) && (size.1 == 17));
     }
 
@@ -148,7 +100,7 @@ mod tests {
         #SE#
         ####
         ";
-        let data = Data::parse(data);
+        let data = Data::load(data);
         assert_eq!(part_1(&data), 1);
 
         let data = "
@@ -156,7 +108,7 @@ mod tests {
         #S.E#
         #####
         ";
-        let data = Data::parse(data);
+        let data = Data::load(data);
         assert_eq!(part_1(&data), 2);
 
         let data = "
@@ -165,7 +117,7 @@ mod tests {
         #S#
         ###
         ";
-        let data = Data::parse(data);
+        let data = Data::load(data);
         assert_eq!(part_1(&data), 1001);
 
         let data = "
@@ -175,35 +127,35 @@ mod tests {
         #S#
         ###
         ";
-        let data = Data::parse(data);
+        let data = Data::load(data);
         assert_eq!(part_1(&data), 1002);
     }
 
     #[test]
     fn test_part_1_1() {
         let data = include_str!("../../data/day_16/test_1.txt");
-        let data = Data::parse(data);
-        assert_eq!(part_1(&data), 7036);
+        let data = Data::load(data);
+        assert_eq!(part_1(&data).to_string(), expected::<Day16>("test_1", 1));
     }
 
     #[test]
     fn test_part_1_2() {
         let data = include_str!("../../data/day_16/test_2.txt");
-        let data = Data::parse(data);
-        assert_eq!(part_1(&data), 11048);
+        let data = Data::load(data);
+        assert_eq!(part_1(&data).to_string(), expected::<Day16>("test_2", 1));
     }
 
     #[test]
     fn test_part_2_1() {
         let data = include_str!("../../data/day_16/test_1.txt");
-        let data = Data::parse(data);
-        assert_eq!(part_2(&data), 45);
+        let data = Data::load(data);
+        assert_eq!(part_2(&data).to_string(), expected::<Day16>("test_1", 2));
     }
 
     #[test]
     fn test_part_2_2() {
         let data = include_str!("../../data/day_16/test_2.txt");
-        let data = Data::parse(data);
-        assert_eq!(part_2(&data), 64);
+        let data = Data::load(data);
+        assert_eq!(part_2(&data).to_string(), expected::<Day16>("test_2", 2));
     }
 }
@@ -1,5 +1,8 @@
+use crate::aoc::parse::{parse_at, ParseError};
+use crate::aoc::solution::{Example, Solution};
+
 #[derive(Clone, Debug)]
-struct Computer {
+pub struct Computer {
     ip: usize,
     a: u64,
     b: u64,
@@ -13,23 +16,24 @@ impl Computer {
         Self {a, b, c, program, ip: 0, out: String::new()}
     }
 
-    fn parse(data: &str) -> Option<Self> {
-        let mut data = data.trim().lines();
-        let a = data.next()?.trim().trim_start_matches("Register A: ").parse().ok()?;
-        let b = data.next()?.trim().trim_start_matches("Register B: ").parse().ok()?;
-        let c = data.next()?.trim().trim_start_matches("Register C: ").parse().ok()?;
-        data.next();
-        let program: Vec<_> = data.next()?.trim().trim_start_matches("Program: ").split(",").map(|s| s.parse()).collect();
-        if program.iter().any(|b| b.is_err()) {
-            return None;
-        }
-        let program: Vec<_> = program.into_iter().map(|b| b.unwrap()).collect();
-        if program.iter().any(|b| *b > 7) {
-            return None;
-        }
-        Some(Self::new(a, b, c, program))
+    fn parse(data: &str) -> Result<Self, ParseError> {
+        let mut lines = data.trim().lines().map(|s| s.trim());
+        let mut next_line = |expected: &str| lines.next().ok_or_else(|| ParseError::after(data, data.trim_end(), expected));
+        let a = parse_at(data, next_line("register A")?.trim_start_matches("Register A: "), "a register value")?;
+        let b = parse_at(data, next_line("register B")?.trim_start_matches("Register B: "), "a register value")?;
+        let c = parse_at(data, next_line("register C")?.trim_start_matches("Register C: "), "a register value")?;
+        next_line("an empty line")?;
+        let program = next_line("a program")?.trim_start_matches("Program: ");
+        let program: Result<Vec<_>, _> = program.split(",").map(|s| {
+            match parse_at(data, s, "a 3 bit number")? {
+                b @ 0..=7 => Ok(b),
+                _ => Err(ParseError::at(data, s, "a 3 bit number"))
+            }
+        }).collect();
+        Ok(Self::new(a, b, c, program?))
     }
 
+    #[cfg(test)]
     fn load(data: &str) -> Self {
         Self::parse(data).expect("vaalid input")
     }
@@ -148,9 +152,8 @@ impl std::fmt::Display for Computer {
     }
 }
 
-fn part_1() -> String {
-    let computer = include_str!("../../data/day_17/input.txt");
-    let mut computer = Computer::parse(computer).expect("valid input");
+fn part_1(computer: &Computer) -> String {
+    let mut computer = computer.clone();
     computer.run().to_string()
 }
 
@@ -184,20 +187,37 @@ fn solve_part_2(computer: &Computer) -> Option<u64> {
     }
 }
 
-fn part_2() -> u64 {
-    let computer = include_str!("../../data/day_17/input.txt");
-    let computer = Computer::load(computer);
-    solve_part_2(&computer).expect("solvable puzzle")
+fn part_2(computer: &Computer) -> u64 {
+    solve_part_2(computer).expect("solvable puzzle")
 }
 
-pub(crate) fn solve() {
-    println!("part 1: {}", part_1());
-    println!("part 2: {}", part_2());
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    const EXAMPLES: &'static [Example] = &[Example::new("test_1", Some("4,6,3,5,6,3,5,2,1,0"), None)];
+
+    type Puzzle = Computer;
+    type Part1 = String;
+    type Part2 = u64;
+
+    fn parse(data: &str) -> Result<Computer, ParseError> {
+        Computer::parse(data)
+    }
+
+    fn part_1(puzzle: &Computer) -> String {
+        part_1(puzzle)
+    }
+
+    fn part_2(puzzle: &Computer) -> u64 {
+        part_2(puzzle)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc::solution::expected;
 
     #[test]
     fn test_data() {
@@ -238,16 +258,20 @@ mod tests {
 
         let computer = include_str!("../../data/day_17/test_1.txt");
         let mut computer = Computer::parse(computer).unwrap();
-        assert_eq!(computer.run(), "4,6,3,5,6,3,5,2,1,0");
+        assert_eq!(computer.run(), expected::<Day17>("test_1", 1));
     }
 
     #[test]
     fn test_part_1() {
-        assert_eq!(part_1(), "7,0,7,3,4,1,3,0,1");
+        let computer = include_str!("../../data/day_17/input.txt");
+        let computer = Computer::load(computer);
+        assert_eq!(part_1(&computer), "7,0,7,3,4,1,3,0,1");
     }
 
     #[test]
     fn test_part_2() {
-        assert_eq!(part_2(), 156985331222018);
+        let computer = include_str!("../../data/day_17/input.txt");
+        let computer = Computer::load(computer);
+        assert_eq!(part_2(&computer), 156985331222018);
     }
 }
@@ -1,18 +1,21 @@
 use std::collections::{HashMap, VecDeque};
+use crate::aoc::parse::ParseError;
+use crate::aoc::solution::{Example, Solution};
 
 #[derive(Debug)]
-struct Data {
+pub struct Data {
     patterns: Vec<String>,
     designs: Vec<String>
 }
 
 impl Data {
-    fn parse(data: &str) -> Option<Self> {
-        let mut data = data.trim().lines();
-        let patterns = data.next()?.split(", ").map(String::from).collect();
-        data.next();
-        let designs = data.map(String::from).collect();
-        Some(Data {patterns, designs})
+    fn parse(data: &str) -> Result<Self, ParseError> {
+        let mut lines = data.trim().lines();
+        let patterns = lines.next().ok_or_else(|| ParseError::at(data, data, "towel patterns"))?;
+        let patterns = patterns.split(", ").map(String::from).collect();
+        lines.next();
+        let designs = lines.map(String::from).collect();
+        Ok(Data {patterns, designs})
     }
 }
 
@@ -43,17 +46,33 @@ fn part_2(data: &Data) -> usize {
     data.designs.iter().map(|d| count(d, &data.patterns)).sum()
 }
 
-pub(crate) fn solve() {
-    let data = include_str!("../../data/day_19/input.txt");
-    let data = Data::parse(data).expect("bad input");
+pub struct Day19;
 
-    println!("part 1: {}", part_1(&data));
-    println!("part 2: {}", part_2(&data));
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    const EXAMPLES: &'static [Example] = &[Example::new("test", Some("6"), Some("16"))];
+
+    type Puzzle = Data;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(data: &str) -> Result<Data, ParseError> {
+        Data::parse(data)
+    }
+
+    fn part_1(puzzle: &Data) -> usize {
+        part_1(puzzle)
+    }
+
+    fn part_2(puzzle: &Data) -> usize {
+        part_2(puzzle)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc::solution::expected;
 
     #[test]
     fn test_data() {
@@ -67,13 +86,13 @@ mod tests {
     fn test_part_1() {
         let data = include_str!("../../data/day_19/test.txt");
         let data = Data::parse(data).unwrap();
-        assert_eq!(part_1(&data), 6);
+        assert_eq!(part_1(&data).to_string(), expected::<Day19>("test", 1));
     }
 
     #[test]
     fn test_part_2() {
         let data = include_str!("../../data/day_19/test.txt");
         let data = Data::parse(data).unwrap();
-        assert_eq!(part_2(&data), 16);
+        assert_eq!(part_2(&data).to_string(), expected::<Day19>("test", 2));
     }
 }
@@ -1,16 +1,17 @@
 use std::collections::HashMap;
 
-use crate::aoc::{grid::Grid, maze::Maze};
+use crate::aoc::{maze::Maze, parse::ParseError, solution::Solution};
 
-struct Puzzle {
+pub struct Puzzle {
     maze: Maze
 }
 
 impl Puzzle {
-    fn parse(data: &str) -> Option<Self> {
-        Some(Self {maze: Maze::parse(data)?})
+    fn parse(data: &str) -> Result<Self, ParseError> {
+        Ok(Self {maze: Maze::parse(data)?})
     }
 
+    #[cfg(test)]
     fn load(data: &str) -> Self {
         Self::parse(data).expect("valid input")
     }
@@ -18,28 +19,22 @@ impl Puzzle {
 
 fn get_cheats(maze: &Maze, cheat: usize, save: usize) -> Option<Vec<(usize, ((usize, usize), (usize, usize)))>> {
     let map = maze.get_map();
-    let begin = map.find('S')?;
-    let end = map.find('E')?;
+    let begin = maze.start()?;
+    let end = maze.end()?;
 
-    let mut distances = Grid::new(maze.get_map().size(), usize::MAX);
-    for (p, _, d) in maze.explore(end, '#') {
-        distances.set(p, d);
-    }
-    let distance = distances.get(begin);
+    let distances = maze.distances(begin, end);
+    let distance = distances.get_distance()?;
 
     let mut cheats = HashMap::new();
-    for (d, p) in maze.get_path(begin, end, '#')?.enumerate() {
+    for (d, p) in maze.get_path(begin, end)?.enumerate() {
         for (cp, _, cd) in map.explore(p, |_, _, _| true).skip(1) {
             if cd > cheat {break;}
             if (cd == 1) && map.get(cp) != '#' {continue;}
-            if !cheats.contains_key(&(p, cp)) {
-                let de = distances.get(cp);
-                if de != usize::MAX {
-                    let d = d + cd + de;
-                    if distance > d {
-                        cheats.insert((p, cp), distance - d);
-                    }
-                }
+            if cheats.contains_key(&(p, cp)) {continue;}
+            let Some(de) = distances.to_end.get(cp) else {continue;};
+            let d = d + cd + de;
+            if distance > d {
+                cheats.insert((p, cp), distance - d);
             }
         }
     }
@@ -62,11 +57,26 @@ fn solve_part_2(puzzle: &Puzzle) -> usize {
 
 }
 
-pub(crate) fn solve() {
-    let puzzle = include_str!("../../data/day_20/input.txt");
-    let puzzle = Puzzle::load(puzzle);
-    println!("part 1: {}", solve_part_1(&puzzle));
-    println!("part 2: {:?}", solve_part_2(&puzzle));
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Puzzle = Puzzle;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(data: &str) -> Result<Puzzle, ParseError> {
+        Puzzle::parse(data)
+    }
+
+    fn part_1(puzzle: &Puzzle) -> usize {
+        solve_part_1(puzzle)
+    }
+
+    fn part_2(puzzle: &Puzzle) -> usize {
+        solve_part_2(puzzle)
+    }
 }
 
 #[cfg(test)]
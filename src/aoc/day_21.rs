@@ -1,3 +1,6 @@
+use crate::aoc::parse::ParseError;
+use crate::aoc::solution::Solution;
+
 /*
 numeric keypad
 
@@ -61,7 +64,7 @@ impl DirPad {
     }
 }
 
-struct Puzzle;
+pub struct Puzzle;
 
 fn part_1(puzzle: &Puzzle) -> usize {
     todo!("part 1");
@@ -71,11 +74,26 @@ fn part_2(puzzle: &Puzzle) -> usize {
     todo!("part 2");
 }
 
-pub(crate) fn solve() {
-    // let puzzle = Puzzle::load();
-    let puzzle = Puzzle {};
-    part_1(&puzzle);
-    part_2(&puzzle);
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+
+    type Puzzle = Puzzle;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(_data: &str) -> Result<Puzzle, ParseError> {
+        Ok(Puzzle)
+    }
+
+    fn part_1(puzzle: &Puzzle) -> usize {
+        part_1(puzzle)
+    }
+
+    fn part_2(puzzle: &Puzzle) -> usize {
+        part_2(puzzle)
+    }
 }
 
 #[cfg(test)]
@@ -1,16 +1,19 @@
 use std::collections::{HashMap, HashSet};
+use crate::aoc::parse::{parse_at, ParseError};
+use crate::aoc::solution::{Example, Solution};
 
-struct Puzzle {
+pub struct Puzzle {
     secrets: Vec<u64>
 }
 
 impl Puzzle {
-    fn parse(data: &str) -> Option<Self> {
-        let secrets: Result<_, _> = data.trim().lines().map(|s| s.trim().parse()).collect();
-        let secrets = secrets.ok()?;
-        Some(Puzzle {secrets})
+    fn parse(data: &str) -> Result<Self, ParseError> {
+        let secrets: Result<_, _> = data.trim().lines().map(|s| parse_at(data, s.trim(), "a secret number")).collect();
+        let secrets = secrets?;
+        Ok(Puzzle {secrets})
     }
 
+    #[cfg(test)]
     fn load(data: &str) -> Self {
         Self::parse(data).expect("valid input")
     }
@@ -68,16 +71,33 @@ fn part_2(puzzle: &Puzzle) -> u64 {
     part_2_n(puzzle, 2000).0
 }
 
-pub(crate) fn solve() {
-    let data = include_str!("../../data/day_22/input.txt");
-    let puzzle = Puzzle::load(data);
-    println!("part 1:  {}", part_1(&puzzle));
-    println!("part 2:  {}", part_2(&puzzle));
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    const EXAMPLES: &'static [Example] = &[Example::new("test_1", Some("37327623"), None), Example::new("test_2", None, Some("23"))];
+
+    type Puzzle = Puzzle;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(data: &str) -> Result<Puzzle, ParseError> {
+        Puzzle::parse(data)
+    }
+
+    fn part_1(puzzle: &Puzzle) -> u64 {
+        part_1(puzzle)
+    }
+
+    fn part_2(puzzle: &Puzzle) -> u64 {
+        part_2(puzzle)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc::solution::expected;
 
     #[test]
     fn test_get_next_secret() {
@@ -98,7 +118,7 @@ mod tests {
     fn test_part_1() {
         let data = include_str!("../../data/day_22/test_1.txt");
         let puzzle = Puzzle::load(data);
-        assert_eq!(part_1(&puzzle), 37327623);
+        assert_eq!(part_1(&puzzle).to_string(), expected::<Day22>("test_1", 1));
     }
 
     #[test]
@@ -114,6 +134,6 @@ mod tests {
     fn test_part_2() {
         let data = include_str!("../../data/day_22/test_2.txt");
         let puzzle = Puzzle::load(data);
-        assert_eq!(part_2(&puzzle), 23);
+        assert_eq!(part_2(&puzzle).to_string(), expected::<Day22>("test_2", 2));
     }
 }
@@ -1,29 +1,33 @@
 use std::collections::{HashMap, HashSet};
 use crate::aoc::sep::SepIterator;
+use crate::aoc::parse::ParseError;
+use crate::aoc::solution::{Example, Solution};
 
-struct Puzzle {
+pub struct Puzzle {
     connections: Vec<(u16, u16)>
 }
 
 impl Puzzle {
-    fn parse(data: &str) -> Option<Self> {
+    fn parse(data: &str) -> Result<Self, ParseError> {
         let parse_connection = |s: &str| {
-            let (a, b) = s.trim().split_once("-")?;
+            let (a, b) = s.trim().split_once("-").ok_or_else(|| ParseError::at(data, s, "'<computer>-<computer>'"))?;
             let (a, b) = (a.trim(), b.trim());
-            if a.is_ascii() && (a.len() == 2) && b.is_ascii() && (b.len() == 2) {
-                let (a, b) = (a.as_bytes(), b.as_bytes());
-                let a= (a[1] as u16) | ((a[0] as u16) << 8);
-                let b= (b[1] as u16) | ((b[0] as u16) << 8);
-                if a < b {Some((a, b))} else {Some((b, a))}
-            } else {
-                None
+            for c in [a, b] {
+                if !c.is_ascii() || (c.len() != 2) {
+                    return Err(ParseError::at(data, c, "a two letter computer name"));
+                }
             }
+            let (a, b) = (a.as_bytes(), b.as_bytes());
+            let a= (a[1] as u16) | ((a[0] as u16) << 8);
+            let b= (b[1] as u16) | ((b[0] as u16) << 8);
+            if a < b {Ok((a, b))} else {Ok((b, a))}
         };
-        let connections: Option<Vec<_>> = data.trim().lines().map(parse_connection).collect();
+        let connections: Result<Vec<_>, _> = data.trim().lines().map(parse_connection).collect();
         let connections = connections?;
-        Some(Self {connections})
+        Ok(Self {connections})
     }
 
+    #[cfg(test)]
     fn load(data: &str) -> Self {
         Self::parse(data).expect("valid input")
     }
@@ -93,43 +97,60 @@ fn part_2(puzzle: &Puzzle) -> String {
     password
 }
 
-pub(crate) fn solve() {
-    let data = include_str!("../../data/day_23/input.txt");
-    let puzzle = Puzzle::load(data);
-    println!("part 1: {}", part_1(&puzzle));
-    println!("part 2: {}", part_2(&puzzle));
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    const EXAMPLES: &'static [Example] = &[Example::new("test", Some("7"), Some("co,de,ka,ta"))];
+
+    type Puzzle = Puzzle;
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(data: &str) -> Result<Puzzle, ParseError> {
+        Puzzle::parse(data)
+    }
+
+    fn part_1(puzzle: &Puzzle) -> usize {
+        part_1(puzzle)
+    }
+
+    fn part_2(puzzle: &Puzzle) -> String {
+        part_2(puzzle)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc::solution::expected;
 
     #[test]
     fn test_parse() {
         let data = "ab-cd";
         let puzzle = Puzzle::parse(data);
-        assert!(puzzle.is_some());
+        assert!(puzzle.is_ok());
 
         let data = "ab=cd";
         let puzzle = Puzzle::parse(data);
-        assert!(puzzle.is_none());
+        assert_eq!(puzzle.err(), Some(ParseError::new(1, 1, "'<computer>-<computer>'")));
 
         let data = "ab-cd-ef";
         let puzzle = Puzzle::parse(data);
-        assert!(puzzle.is_none());
+        assert_eq!(puzzle.err(), Some(ParseError::new(1, 4, "a two letter computer name")));
     }
 
     #[test]
     fn test_part_1() {
         let data = include_str!("../../data/day_23/test.txt");
         let puzzle = Puzzle::load(data);
-        assert_eq!(part_1(&puzzle), 7);
+        assert_eq!(part_1(&puzzle).to_string(), expected::<Day23>("test", 1));
     }
 
     #[test]
     fn test_part_2() {
         let data = include_str!("../../data/day_23/test.txt");
         let puzzle = Puzzle::load(data);
-        assert_eq!(part_2(&puzzle), "co,de,ka,ta");
+        assert_eq!(part_2(&puzzle), expected::<Day23>("test", 2));
     }
 }
\ No newline at end of file
@@ -1,4 +1,6 @@
 use std::collections::HashMap;
+use crate::aoc::parse::ParseError;
+use crate::aoc::solution::{Example, Solution};
 
 #[derive(Clone, Copy, Debug)]
 enum Op {
@@ -19,54 +21,58 @@ struct Gate {
     output: u32
 }
 
-struct Puzzle {
+pub struct Puzzle {
     wires: Vec<Wire>,
     gates: Vec<Gate>
 }
 
 impl Puzzle {
-    fn parse(data: &str) -> Option<Self> {
-        let data = data.trim().replace("\r", "");
+    fn parse(data: &str) -> Result<Self, ParseError> {
+        let data = data.replace("\r", "");
+        let data = data.as_str();
         let parse_wire = |s: &str| {
-            let (name, state) = s.split_once(": ")?;
+            let (name, state) = s.split_once(": ").ok_or_else(|| ParseError::at(data, s, "'<wire>: <state>'"))?;
             if name.len() != 3 {
-                return None;
+                return Err(ParseError::at(data, name, "a three letter wire name"));
             }
             if (state != "0") && (state != "1") {
-                return None;
+                return Err(ParseError::at(data, state, "'0' or '1'"));
             }
             let name = Self::encode_name(name);
             let state = state == "1";
-            Some(Wire {name, state})
+            Ok(Wire {name, state})
         };
         let parse_gate = |s: &str| {
-            let (aob, output) = s.split_once(" -> ")?;
-            let output = Self::encode_name(output);
+            let (aob, output) = s.split_once(" -> ").ok_or_else(|| ParseError::at(data, s, "'<wire> <op> <wire> -> <wire>'"))?;
             let mut aob = aob.split(" ");
-            let a = aob.next()?;
-            let op = aob.next()?;
-            let b = aob.next()?;
-            if aob.next().is_some() {return None;}
-            if (a.len() != 3) || (a.len() != 3) {return None;}
+            let a = aob.next().ok_or_else(|| ParseError::at(data, s, "an input wire"))?;
+            let op = aob.next().ok_or_else(|| ParseError::after(data, a, "an operator"))?;
+            let b = aob.next().ok_or_else(|| ParseError::after(data, op, "an input wire"))?;
+            if let Some(x) = aob.next() {return Err(ParseError::at(data, x, "'->'"));}
+            for w in [a, b, output] {
+                if w.len() != 3 {return Err(ParseError::at(data, w, "a three letter wire name"));}
+            }
             let a = Self::encode_name(a);
             let b = Self::encode_name(b);
+            let output = Self::encode_name(output);
             let op = match op {
                 "AND" => Op::And,
                 "OR"  => Op::Or,
                 "XOR" => Op::Xor,
-                _ => return None
+                _ => return Err(ParseError::at(data, op, "'AND', 'OR' or 'XOR'"))
             };
             let inputs = (a, b);
-            Some(Gate {op, inputs, output})
+            Ok(Gate {op, inputs, output})
         };
-        let (wires, gates) = data.split_once("\n\n")?;
-        let wires: Option<_> = wires.trim().lines().map(parse_wire).collect();
+        let (wires, gates) = data.trim().split_once("\n\n").ok_or_else(|| ParseError::after(data, data.trim_end(), "an empty line before the gates"))?;
+        let wires: Result<_, _> = wires.trim().lines().map(parse_wire).collect();
         let wires = wires?;
-        let gates: Option<_> = gates.trim().lines().map(parse_gate).collect();
+        let gates: Result<_, _> = gates.trim().lines().map(parse_gate).collect();
         let gates = gates?;
-        Some(Puzzle {wires, gates})
+        Ok(Puzzle {wires, gates})
     }
  
+    #[cfg(test)]
     fn load(data: &str) -> Self {
         Self::parse(data).expect("valid input")
     }
@@ -138,21 +144,38 @@ fn solve_part_2(puzzle: &Puzzle) -> u32 {
     todo!("part 2")
 }
 
-pub(crate) fn solve() {
-    let puzzle = include_str!("../../data/day_24/input.txt");
-    let puzzle = Puzzle::load(puzzle);
-    println!("part 1: {}", solve_part_1(&puzzle));
-    println!("part 2: {}", solve_part_2(&puzzle));
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    const EXAMPLES: &'static [Example] = &[Example::new("test", Some("2024"), None)];
+
+    type Puzzle = Puzzle;
+    type Part1 = u64;
+    type Part2 = u32;
+
+    fn parse(data: &str) -> Result<Puzzle, ParseError> {
+        Puzzle::parse(data)
+    }
+
+    fn part_1(puzzle: &Puzzle) -> u64 {
+        solve_part_1(puzzle)
+    }
+
+    fn part_2(puzzle: &Puzzle) -> u32 {
+        solve_part_2(puzzle)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc::solution::expected;
 
     #[test]
     fn test_part_1() {
         let puzzle = include_str!("../../data/day_24/test.txt");
         let puzzle = Puzzle::load(puzzle);
-        assert!(solve_part_1(&puzzle) == 2024);
+        assert_eq!(solve_part_1(&puzzle).to_string(), expected::<Day24>("test", 1));
     }
 }
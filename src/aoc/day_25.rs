@@ -1,70 +1,50 @@
 use crate::aoc::grid::Grid;
+use crate::aoc::parse::ParseError;
+use crate::aoc::solution::{Example, Solution};
 
-struct Puzzle
+pub struct Puzzle
 {
     locks: Vec<[u8; 5]>,
     keys: Vec<[u8; 5]>
 }
 
 impl Puzzle {
-    fn parse(data: &str) -> Option<Self> {
+    fn parse(data: &str) -> Result<Self, ParseError> {
         #[derive(PartialEq)]
         enum LK {L, K}
         struct LKS {
             lk: LK,
             schema: [u8; 5]
         }
+        let data = data.replace("\r", "");
         let parse_lk = |d: &str| {
-            let grid: Grid<char> = Grid::parse(d, "")?;
-            if grid.size() != (5, 7) {return None;}
-            if grid.get((0, 0)) == grid.get((0, 6)) {return None;}
-            if (1..5).any(|x| grid.get((x, 0)) != grid.get((0, 0))) {return None;}
-            if (1..5).any(|x| grid.get((x, 6)) != grid.get((0, 6))) {return None;}
-            for y in 0..7 {
-                for x in 0..5 {
-                    if !"#.".contains(grid.get((x, y))) {
-                        return None;
-                    }
-                }
+            let invalid = || ParseError::at(&data, d, "a 5x7 lock or key schematic");
+            let grid: Grid<char> = Grid::parse(d, "").map_err(|_| invalid())?;
+            if grid.size() != (5, 7) {return Err(invalid());}
+            let lk = if grid.get((0, 0)) == '#' {LK::L} else {LK::K};
+            // keys are locks upside down
+            let grid = if lk == LK::L {grid} else {grid.flip_v()};
+            let mut schema = [0u8; 5];
+            for (x, c) in grid.columns().enumerate() {
+                let c: String = c.collect();
+                let n = c.trim_end_matches('.').len();
+                if !(1..7).contains(&n) || c[..n].contains(|c| c != '#') {return Err(invalid());}
+                schema[x] = (n - 1) as u8;
             }
-            let lks: LKS = if grid.get((0, 0)) == '#' {
-                let mut s = [0u8; 5];
-                for x in 0..5 {
-                    for y in 1..6 {
-                        if grid.get((x, y)) != '#' {break;}
-                        s[x] += 1;
-                    }
-                    for y in (s[x]as usize + 1)..6 {
-                        if grid.get((x, y)) != '.' {return None;}
-                    }
-                }
-                LKS {lk: LK::L, schema: s}
-            } else {
-                let mut s = [0u8; 5];
-                for x in 0..5 {
-                    for y in 1..6 {
-                        if grid.get((x, 6 - y)) != '#' {break;}
-                        s[x] += 1;
-                    }
-                    for y in (s[x]as usize + 1)..6 {
-                        if grid.get((x, 6 - y)) != '.' {return None;}
-                    }
-                }
-                LKS {lk: LK::K, schema: s}
-            };
-            Some(lks)
+            let lks = LKS {lk, schema};
+            Ok(lks)
         };
-        let data = data.trim().replace("\r", "");
-        let lkss: Option<Vec<_>> = data.split("\n\n").map(parse_lk).collect();
+        let lkss: Result<Vec<_>, _> = data.trim().split("\n\n").map(parse_lk).collect();
         let lkss = lkss?;
         let (locks, keys): (Vec<_>, Vec<_>) = lkss.iter().partition(|lks| lks.lk == LK::L);
         let mut locks: Vec<_> = locks.into_iter().map(|lks| lks.schema).collect();
         let mut keys: Vec<_> = keys.into_iter().map(|lks| lks.schema).collect();
         locks.sort();
         keys.sort();
-        Some(Puzzle {locks, keys})
+        Ok(Puzzle {locks, keys})
     }
  
+    #[cfg(test)]
     fn load(data: &str) -> Self {
         Self::parse(data).expect("valid input")
     }
@@ -82,21 +62,38 @@ fn solve_part_2(puzzle: &Puzzle) -> u32 {
     todo!("part 2")
 }
 
-pub(crate) fn solve() {
-    let data = include_str!("../../data/day_25/input.txt");
-    let puzzle = Puzzle::load(data);
-    println!("part 1: {}", solve_part_1(&puzzle));
-    println!("part 2: {}", solve_part_2(&puzzle));
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    const EXAMPLES: &'static [Example] = &[Example::new("test", Some("3"), None)];
+
+    type Puzzle = Puzzle;
+    type Part1 = usize;
+    type Part2 = u32;
+
+    fn parse(data: &str) -> Result<Puzzle, ParseError> {
+        Puzzle::parse(data)
+    }
+
+    fn part_1(puzzle: &Puzzle) -> usize {
+        solve_part_1(puzzle)
+    }
+
+    fn part_2(puzzle: &Puzzle) -> u32 {
+        solve_part_2(puzzle)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc::solution::expected;
 
     #[test]
     fn test_part_1() {
         let data = include_str!("../../data/day_25/test.txt");
         let puzzle = Puzzle::load(data);
-        assert_eq!(solve_part_1(&puzzle), 3);
+        assert_eq!(solve_part_1(&puzzle).to_string(), expected::<Day25>("test", 1));
     }
 }
@@ -1,51 +1,72 @@
-struct Puzzle;
+use crate::aoc::parse::ParseError;
+use crate::aoc::solution::{Example, Solution};
+
+pub struct Puzzle;
 
 impl Puzzle {
-    fn parse(data: &str) -> Option<Self> {
-        None
+    fn parse(_data: &str) -> Result<Self, ParseError> {
+        Ok(Puzzle)
     }
- 
+
+    #[cfg(test)]
     fn load(data: &str) -> Self {
         Self::parse(data).expect("valid input")
     }
 }
 
-fn solve_part_1(puzzle: &Puzzle) -> u32 {
+fn part_1(_puzzle: &Puzzle) -> u32 {
     todo!("part 1")
 }
 
-fn solve_part_2(puzzle: &Puzzle) -> u32 {
+fn part_2(_puzzle: &Puzzle) -> u32 {
     todo!("part 2")
 }
 
-pub fn solve() {
-    let puzzle = include_str!("../../data/day_xx/input.txt");
-    let puzzle = Puzzle::load(puzzle);
-    println!("part 1: {}", solve_part_1(&puzzle));
-    println!("part 2: {}", solve_part_2(&puzzle));
+pub struct DayXX;
+
+impl Solution for DayXX {
+    const DAY: u8 = 0;
+    const EXAMPLES: &'static [Example] = &[Example::new("test", Some("0"), Some("0"))];
+
+    type Puzzle = Puzzle;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(data: &str) -> Result<Puzzle, ParseError> {
+        Puzzle::parse(data)
+    }
+
+    fn part_1(puzzle: &Puzzle) -> u32 {
+        part_1(puzzle)
+    }
+
+    fn part_2(puzzle: &Puzzle) -> u32 {
+        part_2(puzzle)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc::solution::expected;
 
     #[test]
-    fn test_data() {
-        let puzzle = include_str!("../../data/day_xx/test.txt");
-        let puzzle = parse_puzzle(puzzle);
+    fn test_parse() {
+        let data = include_str!("../../data/day_xx/test.txt");
+        assert!(Puzzle::parse(data).is_ok());
     }
 
     #[test]
     fn test_part_1() {
-        let puzzle = include_str!("../../data/day_xx/test.txt");
-        let puzzle = parse_puzzle(puzzle);
-        assert!(part_1(&puzzle) == 0);
+        let data = include_str!("../../data/day_xx/test.txt");
+        let puzzle = Puzzle::load(data);
+        assert_eq!(part_1(&puzzle).to_string(), expected::<DayXX>("test", 1));
     }
 
     #[test]
     fn test_part_2() {
-        let puzzle = include_str!("../../data/day_xx/test.txt");
-        let puzzle = parse_puzzle(puzzle);
-        assert!(part_2(&puzzle) == 0);
+        let data = include_str!("../../data/day_xx/test.txt");
+        let puzzle = Puzzle::load(data);
+        assert_eq!(part_2(&puzzle).to_string(), expected::<DayXX>("test", 2));
     }
 }
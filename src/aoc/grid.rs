@@ -1,6 +1,8 @@
 use std::collections::VecDeque;
 use std::cmp::PartialEq;
 
+use crate::aoc::parse::ParseError;
+
 #[derive(Clone)]
 pub struct Grid<T> {
     size: (usize, usize),
@@ -38,6 +40,93 @@ impl<T: Copy + PartialEq> Grid<T> {
         self.data[point.1 * self.size.0 + point.0] = v;
     }
 
+    pub fn contains(&self, p: Point) -> bool {
+        p.is_in(self.size)
+    }
+
+    pub fn try_get(&self, p: Point) -> Option<T> {
+        self.contains(p).then(|| self.get(p.to_cell()))
+    }
+
+    pub fn step(&self, p: Point, d: impl Into<Vector>) -> Option<Point> {
+        p.step_in(d, self.size)
+    }
+
+    // cells joined by sep and right aligned to pad, for printing with '{}'
+    pub fn display<'a>(&'a self, sep: &'a str, pad: usize) -> GridDisplay<'a, T> {
+        GridDisplay {grid: self, sep, pad}
+    }
+
+    pub fn render_with<F: FnMut(Point, T) -> char>(&self, mut f: F) -> String {
+        let (w, h) = self.size;
+        let mut s = String::with_capacity((w + 1) * h);
+        for y in 0..h {
+            if y > 0 {
+                s.push('\n');
+            }
+            for x in 0..w {
+                s.push(f(Point::from((x, y)), self.get((x, y))));
+            }
+        }
+        s
+    }
+
+    // the orthogonal neighbors of p inside the grid with their values
+    pub fn neighbors4(&self, p: Point) -> impl Iterator<Item = (Point, T)> + '_ {
+        Direction::ALL.into_iter().filter_map(move |d| {
+            self.step(p, d).map(|n| (n, self.get(n.to_cell())))
+        })
+    }
+
+    // same with the diagonals
+    pub fn neighbors8(&self, p: Point) -> impl Iterator<Item = (Point, T)> + '_ {
+        Direction8::ALL.into_iter().filter_map(move |d| {
+            self.step(p, d).map(|n| (n, self.get(n.to_cell())))
+        })
+    }
+
+    // connected components of the cells where same(a, b) holds between neighbors,
+    // labeled in reading order of their first cell
+    pub fn regions<F: FnMut(T, T) -> bool>(&self, mut same: F) -> Regions {
+        let mut labels = Grid::new(self.size, usize::MAX);
+        let mut regions = Vec::new();
+        for (c, v) in self.cells() {
+            if labels.get(c) != usize::MAX {continue;}
+            let label = regions.len();
+            let start = Point::from(c);
+            let mut r = Region {label, start, area: 0, perimeter: 0, sides: 0, min: start, max: start};
+            labels.set(c, label);
+            let mut s = vec![(start, v)];
+            while let Some((p, v)) = s.pop() {
+                r.area += 1;
+                r.min = Point::new(r.min.x.min(p.x), r.min.y.min(p.y));
+                r.max = Point::new(r.max.x.max(p.x), r.max.y.max(p.y));
+                for (n, nv) in self.neighbors4(p) {
+                    if labels.get(n.to_cell()) == usize::MAX && same(v, nv) {
+                        labels.set(n.to_cell(), label);
+                        s.push((n, nv));
+                    }
+                }
+            }
+            regions.push(r);
+        }
+
+        // a border starts a side unless the cell on its left has the same border
+        for (c, label) in labels.cells() {
+            let p = Point::from(c);
+            let is_in = |p: Point| labels.try_get(p) == Some(label);
+            for d in Direction::ALL {
+                if is_in(p.step(d)) {continue;}
+                regions[label].perimeter += 1;
+                let q = p.step(d.turn_left());
+                if !is_in(q) || is_in(q.step(d)) {
+                    regions[label].sides += 1;
+                }
+            }
+        }
+        Regions {regions, labels}
+    }
+
     pub fn find(&self, v: T) -> Option<(usize, usize)> {
         let (w, h) = self.size();
         for y in 0..h {
@@ -69,7 +158,7 @@ impl<T: Copy + PartialEq> Grid<T> {
     //         GridExploreIterator::new(self, start, filter)
     // }
 
-    pub fn explore<F> (&self, start: (usize, usize), filter: F) -> GridExploreIterator<T, F>
+    pub fn explore<F> (&self, start: (usize, usize), filter: F) -> GridExploreIterator<'_, T, F>
     where F: FnMut((usize, usize), (usize, usize), usize) -> bool
     {
         GridExploreIterator::new(self, start, filter)
@@ -100,7 +189,7 @@ impl<T: Copy + PartialEq> Grid<T> {
     pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = T> + '_> {
         let mut i = 0;
         std::iter::from_fn(move || {
-            if i < self.size().0 {
+            if i < self.size().1 {
                 let r = self.row(i);
                 i += 1;
                 Some(r)
@@ -135,6 +224,116 @@ impl<T: Copy + PartialEq> Grid<T> {
             }
         })
     }
+
+    // diagonals going down to the right, from the bottom left corner to the top right one
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = T> + '_> + '_ {
+        let (w, h) = self.size;
+        (0..(w + h - 1)).map(move |k| {
+            let (x, y) = if k < h {(0, h - 1 - k)} else {(k + 1 - h, 0)};
+            (0..(w - x).min(h - y)).map(move |i| self.get((x + i, y + i)))
+        })
+    }
+
+    // diagonals going up to the right, from the top left corner to the bottom right one
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = T> + '_> + '_ {
+        let (w, h) = self.size;
+        (0..(w + h - 1)).map(move |k| {
+            let (x, y) = if k < h {(0, k)} else {(k + 1 - h, h - 1)};
+            (0..(w - x).min(y + 1)).map(move |i| self.get((x + i, y - i)))
+        })
+    }
+
+    fn map_cells<F: Fn(usize, usize) -> (usize, usize)>(&self, size: (usize, usize), f: F) -> Self {
+        let mut data = Vec::with_capacity(self.data.len());
+        for y in 0..size.1 {
+            for x in 0..size.0 {
+                data.push(self.get(f(x, y)));
+            }
+        }
+        Self {size, data}
+    }
+
+    pub fn map<U, F: FnMut(T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {size: self.size, data: self.data.iter().copied().map(f).collect()}
+    }
+
+    pub fn transpose(&self) -> Self {
+        let (w, h) = self.size;
+        self.map_cells((h, w), |x, y| (y, x))
+    }
+
+    pub fn rotate_cw(&self) -> Self {
+        let (w, h) = self.size;
+        self.map_cells((h, w), |x, y| (y, h - 1 - x))
+    }
+
+    pub fn rotate_ccw(&self) -> Self {
+        let (w, h) = self.size;
+        self.map_cells((h, w), |x, y| (w - 1 - y, x))
+    }
+
+    // mirrors left and right
+    pub fn flip_h(&self) -> Self {
+        let (w, h) = self.size;
+        self.map_cells((w, h), |x, y| (w - 1 - x, y))
+    }
+
+    // mirrors top and bottom
+    pub fn flip_v(&self) -> Self {
+        let (w, h) = self.size;
+        self.map_cells((w, h), |x, y| (x, h - 1 - y))
+    }
+
+    pub fn view(&self, x: usize, y: usize, w: usize, h: usize) -> GridView<'_, T> {
+        assert!((w > 0) && (h > 0) && (x + w <= self.size.0) && (y + h <= self.size.1));
+        GridView {grid: self, origin: (x, y), size: (w, h)}
+    }
+}
+
+// a borrowed rectangle of a grid, with its own coordinates
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    origin: (usize, usize),
+    size: (usize, usize)
+}
+
+impl<T: Copy + PartialEq> GridView<'_, T> {
+    pub fn size(&self) -> (usize, usize) {
+        self.size
+    }
+
+    pub fn get(&self, point: (usize, usize)) -> T {
+        debug_assert!((point.0 < self.size.0) && (point.1 < self.size.1));
+        self.grid.get((self.origin.0 + point.0, self.origin.1 + point.1))
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = ((usize, usize), T)> + '_ {
+        let (w, h) = self.size;
+        (0..h).flat_map(move |y| (0..w).map(move |x| ((x, y), self.get((x, y)))))
+    }
+
+    pub fn to_grid(&self) -> Grid<T> {
+        self.grid.map_cells(self.size, |x, y| (self.origin.0 + x, self.origin.1 + y))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    pub label: usize,
+    // the first cell in reading order
+    pub start: Point,
+    pub area: u32,
+    pub perimeter: u32,
+    pub sides: u32,
+    // the bounding box, inclusive
+    pub min: Point,
+    pub max: Point
+}
+
+pub struct Regions {
+    pub regions: Vec<Region>,
+    // the label of the region of each cell
+    pub labels: Grid<usize>
 }
 
 impl<T: Default + Copy + PartialEq> Grid<T> {
@@ -144,46 +343,281 @@ impl<T: Default + Copy + PartialEq> Grid<T> {
 }
 
 impl<T: Copy + PartialEq + std::str::FromStr + std::fmt::Debug> Grid<T> {
-    pub fn parse(data: &str, sep: &str) -> Option<Self> where <T as std::str::FromStr>::Err: std::fmt::Debug {
-        let data: Result<Vec<Vec<_>>, _> = data.trim().lines().map(|r| {
+    pub fn parse(data: &str, sep: &str) -> Result<Self, ParseError> {
+        let parse_cell = |s: &str| T::from_str(s).map_err(|_| ParseError::at(data, s, "a valid cell"));
+        let lines: Vec<_> = data.trim().lines().map(|r| r.trim()).collect();
+        let cells: Result<Vec<Vec<_>>, _> = lines.iter().map(|r| {
             if sep.is_empty() {
-                r.trim().chars().map(|c| T::from_str(&c.to_string())).collect()
+                r.char_indices().map(|(i, c)| parse_cell(&r[i..(i + c.len_utf8())])).collect()
             } else {
-                r.trim().split(sep).map(|s| T::from_str(&s)).collect()
+                r.split(sep).map(parse_cell).collect()
             }
         }).collect();
-        let data = data.ok()?;
-        if !data.is_empty() && !data[0].is_empty() && data.iter().skip(1).all(|r| r.len() == data[0].len()) {
-            Some(Self::from_vec(&data))
-        } else {
-            None
+        let cells = cells?;
+        if cells.is_empty() || cells[0].is_empty() {
+            return Err(ParseError::at(data, data.trim(), "a non empty grid"));
+        }
+        let width = cells[0].len();
+        if let Some(i) = cells.iter().position(|r| r.len() != width) {
+            return Err(ParseError::at(data, lines[i], &format!("a row of {width} cells")));
         }
+        Ok(Self::from_vec(&cells))
     }
 
-    pub fn load(data: &str, sep: &str) -> Self where <T as std::str::FromStr>::Err: std::fmt::Debug {
+    pub fn load(data: &str, sep: &str) -> Self {
         Self::parse(data, sep).expect("valid input")
     }
 }
 
-enum Dir {
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vector {
+    pub x: i32,
+    pub y: i32
+}
+
+impl Point {
+    pub const fn new(x: i32, y: i32) -> Self {
+        Self {x, y}
+    }
+
+    pub fn step(self, d: impl Into<Vector>) -> Self {
+        self + d.into()
+    }
+
+    // None out of a grid of the given size
+    pub fn step_in(self, d: impl Into<Vector>, size: (usize, usize)) -> Option<Self> {
+        Some(self.step(d)).filter(|p| p.is_in(size))
+    }
+
+    pub fn is_in(self, size: (usize, usize)) -> bool {
+        (self.x >= 0) && (self.y >= 0) && ((self.x as usize) < size.0) && ((self.y as usize) < size.1)
+    }
+
+    // the cell of a point already known to be in the grid
+    pub fn to_cell(self) -> (usize, usize) {
+        debug_assert!((self.x >= 0) && (self.y >= 0), "{self:?} out of the grid");
+        (self.x as usize, self.y as usize)
+    }
+
+    pub fn manhattan(self, other: Self) -> u32 {
+        (other - self).manhattan()
+    }
+}
+
+impl Vector {
+    pub const fn new(x: i32, y: i32) -> Self {
+        Self {x, y}
+    }
+
+    pub fn manhattan(self) -> u32 {
+        self.x.unsigned_abs() + self.y.unsigned_abs()
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((x, y): (usize, usize)) -> Self {
+        Self::new(x as i32, y as i32)
+    }
+}
+
+impl std::ops::Add<Vector> for Point {
+    type Output = Point;
+    fn add(self, v: Vector) -> Point {
+        Point::new(self.x + v.x, self.y + v.y)
+    }
+}
+
+impl std::ops::Sub<Vector> for Point {
+    type Output = Point;
+    fn sub(self, v: Vector) -> Point {
+        Point::new(self.x - v.x, self.y - v.y)
+    }
+}
+
+impl std::ops::Sub for Point {
+    type Output = Vector;
+    fn sub(self, p: Point) -> Vector {
+        Vector::new(self.x - p.x, self.y - p.y)
+    }
+}
+
+impl std::ops::AddAssign<Vector> for Point {
+    fn add_assign(&mut self, v: Vector) {
+        *self = *self + v;
+    }
+}
+
+impl std::ops::SubAssign<Vector> for Point {
+    fn sub_assign(&mut self, v: Vector) {
+        *self = *self - v;
+    }
+}
+
+impl std::ops::Add for Vector {
+    type Output = Vector;
+    fn add(self, v: Vector) -> Vector {
+        Vector::new(self.x + v.x, self.y + v.y)
+    }
+}
+
+impl std::ops::Sub for Vector {
+    type Output = Vector;
+    fn sub(self, v: Vector) -> Vector {
+        Vector::new(self.x - v.x, self.y - v.y)
+    }
+}
+
+impl std::ops::Mul<i32> for Vector {
+    type Output = Vector;
+    fn mul(self, n: i32) -> Vector {
+        Vector::new(self.x * n, self.y * n)
+    }
+}
+
+impl std::ops::Neg for Vector {
+    type Output = Vector;
+    fn neg(self) -> Vector {
+        Vector::new(-self.x, -self.y)
+    }
+}
+
+// y growing southward, as the rows of a grid
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
     East,
-    West,
+    South,
+    West
+}
+
+impl Direction {
+    // clockwise from north
+    pub const ALL: [Direction; 4] = [Direction::North, Direction::East, Direction::South, Direction::West];
+
+    pub fn vector(self) -> Vector {
+        match self {
+            Direction::North => Vector::new(0, -1),
+            Direction::East => Vector::new(1, 0),
+            Direction::South => Vector::new(0, 1),
+            Direction::West => Vector::new(-1, 0)
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction::East | Direction::West)
+    }
+
+    // '^', '>', 'v' or '<'
+    pub fn arrow(self) -> char {
+        ['^', '>', 'v', '<'][self as usize]
+    }
+}
+
+// '^', '>', 'v', '<' or 'N', 'E', 'S', 'W'
+impl TryFrom<char> for Direction {
+    type Error = char;
+    fn try_from(c: char) -> Result<Self, char> {
+        match c {
+            '^' | 'N' => Ok(Direction::North),
+            '>' | 'E' => Ok(Direction::East),
+            'v' | 'S' => Ok(Direction::South),
+            '<' | 'W' => Ok(Direction::West),
+            c => Err(c)
+        }
+    }
+}
+
+impl From<Direction> for Vector {
+    fn from(d: Direction) -> Self {
+        d.vector()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
     North,
-    South
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest
 }
 
-impl Dir {
-    fn offset(&self) -> (i32, i32) {
+impl Direction8 {
+    // clockwise from north
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North, Direction8::NorthEast, Direction8::East, Direction8::SouthEast,
+        Direction8::South, Direction8::SouthWest, Direction8::West, Direction8::NorthWest
+    ];
+
+    pub fn vector(self) -> Vector {
         match self {
-            Dir::East => (-1, 0),
-            Dir::West => (1, 0),
-            Dir::North => (0, -1),
-            Dir::South => (0, 1)
+            Direction8::North => Vector::new(0, -1),
+            Direction8::NorthEast => Vector::new(1, -1),
+            Direction8::East => Vector::new(1, 0),
+            Direction8::SouthEast => Vector::new(1, 1),
+            Direction8::South => Vector::new(0, 1),
+            Direction8::SouthWest => Vector::new(-1, 1),
+            Direction8::West => Vector::new(-1, 0),
+            Direction8::NorthWest => Vector::new(-1, -1)
         }
     }
 
-    fn all() -> [Dir; 4] {
-        [Dir::East, Dir::West, Dir::North, Dir::South]
+    // by 45 degrees
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        (self as usize % 2) == 1
+    }
+}
+
+// 'N', 'E', 'S', 'W' and the arrows of the 4 way directions
+impl TryFrom<char> for Direction8 {
+    type Error = char;
+    fn try_from(c: char) -> Result<Self, char> {
+        Direction::try_from(c).map(Direction8::from)
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(d: Direction) -> Self {
+        Self::ALL[d as usize * 2]
+    }
+}
+
+impl From<Direction8> for Vector {
+    fn from(d: Direction8) -> Self {
+        d.vector()
     }
 }
 
@@ -224,14 +658,9 @@ impl<'a, T: Copy + PartialEq, F: FnMut((usize, usize), (usize, usize), usize) ->
 
     fn next(&mut self) -> Option<Self::Item> {
         if let Some((position, pposition, distance)) = self.positions.pop_front() {
-            let (w, h) = {let (w, h) = self.grid.size(); (w as i32, h as i32)};
-            let (x, y) = (position.0 as i32, position.1 as i32);
-            for d in Dir::all() {
-                let (dx, dy) = d.offset();
-                let (x, y) = (x + dx, y + dy);
-                if (x >= 0) && (x < w) && (y >= 0) && (y < h) {
-                    let p = (x as usize, y as usize);
-                    self.visit(p, position, distance + 1);
+            for d in Direction::ALL {
+                if let Some(p) = Point::from(position).step_in(d, self.grid.size()) {
+                    self.visit(p.to_cell(), position, distance + 1);
                 }
             }
             Some((position, pposition, distance))
@@ -242,48 +671,50 @@ impl<'a, T: Copy + PartialEq, F: FnMut((usize, usize), (usize, usize), usize) ->
 }
 
 impl std::str::FromStr for Grid<char> {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let grid: Option<Self> = Grid::parse(s, "");
-        match grid {
-            Some(grid) => Ok(grid),
-            None => Err(())
-        }
+        Grid::parse(s, "")
+    }
+}
+
+// each cell is right aligned to the width of the formatter, '{:3}' pads to 3
+impl<T: Copy + PartialEq + std::fmt::Display> std::fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.display("", f.width().unwrap_or(0)).fmt(f)
     }
 }
 
-impl std::fmt::Display for Grid<char> {
+impl<T: std::fmt::Debug> std::fmt::Debug for Grid<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        let mut nl = false;
-        for y in 0..(self.size.1) {
-            if nl {
-                writeln!(f, "")?;
+        let rows: Vec<_> = self.data.chunks(self.size.0).collect();
+        f.debug_struct("Grid").field("size", &self.size).field("rows", &rows).finish()
+    }
+}
+
+pub struct GridDisplay<'a, T> {
+    grid: &'a Grid<T>,
+    sep: &'a str,
+    pad: usize
+}
+
+impl<T: Copy + PartialEq + std::fmt::Display> std::fmt::Display for GridDisplay<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let (w, h) = self.grid.size;
+        for y in 0..h {
+            if y > 0 {
+                writeln!(f)?;
             }
-            for x in 0..(self.size.0) {
-                write!(f, "{}", self.get((x, y)))?;
+            for x in 0..w {
+                if x > 0 {
+                    write!(f, "{}", self.sep)?;
+                }
+                write!(f, "{:>pad$}", self.grid.get((x, y)), pad = self.pad)?;
             }
-            nl = true;
         }
         Ok(())
     }
 }
 
-// impl<T: std::fmt::Display> std::fmt::Display for Grid<T> {
-//     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-//         let mut nl = false;
-//         for y in 0..(self.height) {
-//             if nl {
-//                 writeln!(f, "")?;
-//             }
-//             for x in 0..(self.width) {
-//                 write!(f, "{}", self.get(x, y))?;
-//             }
-//             nl = true;
-//         }
-//         Ok(())
-//     }
-// }
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -321,8 +752,15 @@ mod tests {
         1X3
         456
         ";
-        let grid: Option<Grid<u8>> = Grid::parse(data, "");
-        assert!(grid.is_none());
+        let grid: Result<Grid<u8>, _> = Grid::parse(data, "");
+        assert_eq!(grid.err(), Some(ParseError::new(2, 10, "a valid cell")));
+
+        let data = "123\n45\n";
+        let grid: Result<Grid<u8>, _> = Grid::parse(data, "");
+        assert_eq!(grid.err(), Some(ParseError::new(2, 1, "a row of 3 cells")));
+
+        let grid: Result<Grid<u8>, _> = Grid::parse(" \n", "");
+        assert_eq!(grid.err(), Some(ParseError::new(1, 1, "a non empty grid")));
     }
 
     #[test]
@@ -403,4 +841,132 @@ mod tests {
         assert_eq!(rows.next().unwrap().collect::<Vec<_>>(), vec!['2', '4']);
         assert!(rows.next().is_none());
     }
+
+    #[test]
+    fn test_point() {
+        let p = Point::new(2, 3);
+        assert_eq!(p + Vector::new(1, -1), Point::new(3, 2));
+        assert_eq!(p - Point::new(5, 5), Vector::new(-3, -2));
+        assert_eq!(-Vector::new(1, -2) * 3, Vector::new(-3, 6));
+        assert_eq!(p.manhattan(Point::new(0, 0)), 5);
+        assert_eq!(p.step(Direction::North), Point::new(2, 2));
+        assert_eq!(p.step(Direction8::SouthWest), Point::new(1, 4));
+        assert_eq!(p.step_in(Direction::East, (3, 4)), None);
+        assert_eq!(p.step_in(Direction::West, (3, 4)), Some(Point::new(1, 3)));
+        assert_eq!(Point::new(0, 0).step_in(Direction::North, (3, 4)), None);
+        assert_eq!(Point::from((1, 2)).to_cell(), (1, 2));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let data = "
+        123
+        456
+        ";
+        let grid: Grid<char> = Grid::load(data, "");
+        assert_eq!(grid.try_get(Point::new(2, 1)), Some('6'));
+        assert_eq!(grid.try_get(Point::new(3, 1)), None);
+        assert_eq!(grid.try_get(Point::new(0, -1)), None);
+        assert_eq!(grid.step(Point::new(0, 0), Direction::South), Some(Point::new(0, 1)));
+        assert_eq!(grid.step(Point::new(0, 0), Direction::West), None);
+        let ns: Vec<_> = grid.neighbors4(Point::new(0, 0)).collect();
+        assert_eq!(ns, vec![(Point::new(1, 0), '2'), (Point::new(0, 1), '4')]);
+        let ns: String = grid.neighbors4(Point::new(1, 1)).map(|(_, c)| c).collect();
+        assert_eq!(ns, "264");
+        let ns: String = grid.neighbors8(Point::new(1, 0)).map(|(_, c)| c).collect();
+        assert_eq!(ns, "36541");
+    }
+
+    #[test]
+    fn test_transform() {
+        let data = "
+        123
+        456
+        ";
+        let grid: Grid<char> = Grid::load(data, "");
+        assert_eq!(grid.transpose().to_string(), "14\n25\n36");
+        assert_eq!(grid.rotate_cw().to_string(), "41\n52\n63");
+        assert_eq!(grid.rotate_ccw().to_string(), "36\n25\n14");
+        assert_eq!(grid.flip_h().to_string(), "321\n654");
+        assert_eq!(grid.flip_v().to_string(), "456\n123");
+        assert_eq!(grid.rotate_cw().rotate_ccw().to_string(), grid.to_string());
+        assert_eq!(grid.map(|c| c.to_digit(10).unwrap() * 2).get((2, 1)), 12);
+
+        let diagonals: Vec<String> = grid.diagonals().map(|d| d.collect()).collect();
+        assert_eq!(diagonals, vec!["4", "15", "26", "3"]);
+        let diagonals: Vec<String> = grid.anti_diagonals().map(|d| d.collect()).collect();
+        assert_eq!(diagonals, vec!["1", "42", "53", "6"]);
+
+        let view = grid.view(1, 0, 2, 2);
+        assert_eq!(view.size(), (2, 2));
+        assert_eq!(view.get((0, 1)), '5');
+        assert_eq!(view.cells().map(|(_, c)| c).collect::<String>(), "2356");
+        assert_eq!(view.to_grid().to_string(), "23\n56");
+    }
+
+    #[test]
+    fn test_regions() {
+        let data = "
+        AAAA
+        BBCD
+        BBCC
+        EEEC
+        ";
+        let grid: Grid<char> = Grid::load(data, "");
+        let Regions {regions, labels} = grid.regions(|a, b| a == b);
+        assert_eq!(regions.len(), 5);
+        assert_eq!(labels.to_string(), "0000\n1123\n1122\n4442");
+        let c = &regions[2];
+        assert_eq!((c.start, c.area, c.perimeter, c.sides), (Point::new(2, 1), 4, 10, 8));
+        assert_eq!((c.min, c.max), (Point::new(2, 1), Point::new(3, 3)));
+        let sides: Vec<_> = regions.iter().map(|r| r.sides).collect();
+        assert_eq!(sides, vec![4, 4, 8, 4, 4]);
+
+        let data = "
+        1 2 5
+        2 9 4
+        ";
+        let grid: Grid<u8> = Grid::load(data, " ");
+        let regions = grid.regions(|a, b| a.abs_diff(b) <= 1).regions;
+        let areas: Vec<_> = regions.iter().map(|r| r.area).collect();
+        assert_eq!(areas, vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn test_display() {
+        let data = "
+        1 2
+        34 5
+        ";
+        let grid: Grid<u8> = Grid::load(data, " ");
+        assert_eq!(grid.to_string(), "12\n345");
+        assert_eq!(format!("{grid:3}"), "  1  2\n 34  5");
+        assert_eq!(grid.display(" ", 2).to_string(), " 1  2\n34  5");
+        assert_eq!(format!("{grid:?}"), "Grid { size: (2, 2), rows: [[1, 2], [34, 5]] }");
+        let s = grid.render_with(|p, v| if v > 2 {'#'} else if p.x == 0 {'a'} else {'b'});
+        assert_eq!(s, "ab\n##");
+
+        let grid: Grid<char> = "ab\ncd".parse().unwrap();
+        assert_eq!(grid.to_string(), "ab\ncd");
+    }
+
+    #[test]
+    fn test_direction() {
+        assert_eq!(Direction::East.vector(), Vector::new(1, 0));
+        assert_eq!(Direction::West.vector(), Vector::new(-1, 0));
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::West.opposite(), Direction::East);
+        assert_eq!(Direction::try_from('v'), Ok(Direction::South));
+        assert_eq!(Direction::try_from('W'), Ok(Direction::West));
+        assert_eq!(Direction::try_from('x'), Err('x'));
+        assert!(Direction::ALL.iter().all(|&d| Direction::try_from(d.arrow()) == Ok(d)));
+
+        assert_eq!(Direction8::North.turn_right(), Direction8::NorthEast);
+        assert_eq!(Direction8::North.turn_left(), Direction8::NorthWest);
+        assert_eq!(Direction8::NorthEast.opposite(), Direction8::SouthWest);
+        assert_eq!(Direction8::try_from('>'), Ok(Direction8::East));
+        assert!(Direction8::SouthEast.is_diagonal());
+        assert!(Direction8::ALL.iter().all(|&d| d.opposite().vector() == -d.vector()));
+    }
 }
@@ -1,4 +1,7 @@
-use crate::aoc::grid::{Grid, GridExploreIterator};
+use std::collections::VecDeque;
+
+use crate::aoc::grid::{Grid, GridExploreIterator, Point};
+use crate::aoc::parse::ParseError;
 
 pub type MazeExploreIterator<'a, F>  = GridExploreIterator<'a, char, F>;
 
@@ -14,13 +17,56 @@ impl Iterator for MazePathIterator {
     }
 }
 
+// the index of the nearest of some sources and the distance to it
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Nearest {
+    pub source: usize,
+    pub distance: usize
+}
+
+// the distances of a maze from its begin and to its end, from two traversals
+pub struct Distances {
+    pub from_begin: Grid<Option<usize>>,
+    pub to_end: Grid<Option<usize>>,
+    end: (usize, usize)
+}
+
+impl Distances {
+    pub fn get_distance(&self) -> Option<usize> {
+        self.from_begin.get(self.end)
+    }
+
+    // the length of the shortest path going through p
+    pub fn through(&self, p: (usize, usize)) -> Option<usize> {
+        Some(self.from_begin.get(p)? + self.to_end.get(p)?)
+    }
+}
+
+// the cells a maze can go through, by default all but '#' so the markers are floor
+pub type Passable = Box<dyn Fn(char) -> bool + Send + Sync>;
+
 pub struct Maze {
-    map: Grid<char>
+    map: Grid<char>,
+    passable: Passable
 }
 
 impl Maze {
-    pub fn parse(data: &str) -> Option<Self> {
-        Some(Self {map: data.parse().ok()?})
+    pub fn new(map: Grid<char>) -> Self {
+        Self {map, passable: Box::new(|c| c != '#')}
+    }
+
+    pub fn parse(data: &str) -> Result<Self, ParseError> {
+        Ok(Self::new(data.parse()?))
+    }
+
+    // the cells holding any of walls block
+    pub fn with_walls(self, walls: &str) -> Self {
+        let walls = walls.to_string();
+        self.with_passable(move |c| !walls.contains(c))
+    }
+
+    pub fn with_passable<F: Fn(char) -> bool + Send + Sync + 'static>(self, passable: F) -> Self {
+        Self {map: self.map, passable: Box::new(passable)}
     }
 
     pub fn load(data: &str) -> Self {
@@ -31,21 +77,39 @@ impl Maze {
         &self.map
     }
 
+    pub fn is_passable(&self, p: (usize, usize)) -> bool {
+        (self.passable)(self.map.get(p))
+    }
+
+    // the 'S' marker
+    pub fn start(&self) -> Option<(usize, usize)> {
+        self.map.find('S')
+    }
+
+    // the 'E' marker
+    pub fn end(&self) -> Option<(usize, usize)> {
+        self.map.find('E')
+    }
+
+    // the passable cells next to p
+    pub fn neighbors(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        self.map.neighbors4(p).filter(|&(_, c)| (self.passable)(c)).map(|(n, _)| n)
+    }
+
     pub fn explore(
         &self,
-        start: (usize, usize),
-        wall: char
-    ) -> MazeExploreIterator<impl FnMut((usize, usize), (usize, usize), usize) -> bool + '_> {
-        MazeExploreIterator::new(self.get_map(), start, move |p, _, _| self.get_map().get(p) != wall)
+        start: (usize, usize)
+    ) -> MazeExploreIterator<'_, impl FnMut((usize, usize), (usize, usize), usize) -> bool + '_> {
+        MazeExploreIterator::new(self.get_map(), start, move |p, _, _| self.is_passable(p))
     }
 
     pub fn get_path(
-        &self, begin: (usize, usize),
-        end: (usize, usize),
-        wall: char
+        &self,
+        begin: (usize, usize),
+        end: (usize, usize)
     ) -> Option<MazePathIterator> {
         let mut pps:Grid<(usize, usize)> = Grid::new(self.map.size(), (0, 0));
-        for (p, pp, _) in self.explore(begin, wall) {
+        for (p, pp, _) in self.explore(begin) {
             pps.set(p, pp);
             if p == end {
                 let mut path = Vec::new();
@@ -66,26 +130,64 @@ impl Maze {
     pub fn get_distance(
         &self,
         begin: (usize, usize),
-        end: (usize, usize),
-        wall: char
+        end: (usize, usize)
     ) -> Option<usize> {
-        for (p, _, d) in self.explore(begin, wall) {
+        for (p, _, d) in self.explore(begin) {
             if p == end {
                 return Some(d);
             }
         }
         None
     }
+
+    // the distance of each cell to its nearest source, none when walled off
+    pub fn distance_field(
+        &self,
+        sources: impl IntoIterator<Item = (usize, usize)>
+    ) -> Grid<Option<usize>> {
+        self.nearest_sources(sources).map(|n| n.map(|n| n.distance))
+    }
+
+    pub fn distance_from(&self, source: (usize, usize)) -> Grid<Option<usize>> {
+        self.distance_field([source])
+    }
+
+    // the nearest source of each cell, ties going to the first source
+    pub fn nearest_sources(
+        &self,
+        sources: impl IntoIterator<Item = (usize, usize)>
+    ) -> Grid<Option<Nearest>> {
+        let mut field = Grid::new(self.map.size(), None);
+        let mut ps = VecDeque::new();
+        for (i, s) in sources.into_iter().enumerate() {
+            if field.get(s).is_none() && self.is_passable(s) {
+                field.set(s, Some(Nearest {source: i, distance: 0}));
+                ps.push_back(Point::from(s));
+            }
+        }
+        while let Some(p) = ps.pop_front() {
+            let Nearest {source, distance} = field.get(p.to_cell()).unwrap();
+            for n in self.neighbors(p) {
+                if field.get(n.to_cell()).is_none() {
+                    field.set(n.to_cell(), Some(Nearest {source, distance: distance + 1}));
+                    ps.push_back(n);
+                }
+            }
+        }
+        field
+    }
+
+    pub fn distances(&self, begin: (usize, usize), end: (usize, usize)) -> Distances {
+        let from_begin = self.distance_from(begin);
+        let to_end = self.distance_from(end);
+        Distances {from_begin, to_end, end}
+    }
 }
 
 impl std::str::FromStr for Maze {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let map = s.parse();
-        match map {
-            Ok(map) => Ok(Maze {map}),
-            Err(_) => Err(())
-        }
+        Self::parse(s)
     }
 }
 
@@ -112,8 +214,9 @@ mod tests {
         #####
         ";
         let maze = Maze::load(data);
-        assert_eq!(maze.explore((2, 1), '#').count(), 3);
-        assert_eq!(maze.explore((2, 1), '.').count(), 0);
+        assert_eq!(maze.explore((2, 1)).count(), 3);
+        let maze = maze.with_walls(".");
+        assert_eq!(maze.explore((2, 1)).count(), 0);
     }
 
     #[test]
@@ -125,9 +228,10 @@ mod tests {
         #####
         ";
         let maze = Maze::load(data);
-        let map = &maze.get_map();
-        assert_eq!(maze.get_distance(map.find('B').unwrap(), map.find('E').unwrap(), '#').unwrap(), 4);
-        assert_eq!(maze.get_distance(map.find('B').unwrap(), map.find('E').unwrap(), 'X').unwrap(), 2);
+        let (begin, end) = (maze.get_map().find('B').unwrap(), maze.end().unwrap());
+        assert_eq!(maze.get_distance(begin, end).unwrap(), 4);
+        let maze = maze.with_walls("X");
+        assert_eq!(maze.get_distance(begin, end).unwrap(), 2);
 
         let data = "
         #######
@@ -138,7 +242,7 @@ mod tests {
         ";
         let maze = Maze::load(data);
         let map = &maze.get_map();
-        assert_eq!(maze.get_distance(map.find('B').unwrap(), map.find('E').unwrap(), '#').unwrap(), 8);
+        assert_eq!(maze.get_distance(map.find('B').unwrap(), map.find('E').unwrap()).unwrap(), 8);
 
         let data = "
         #########
@@ -150,7 +254,51 @@ mod tests {
         let maze = Maze::load(data);
         let begin = maze.get_map().find('B').unwrap();
         let end = maze.get_map().find('E').unwrap();
-        assert_eq!(maze.get_distance(begin, end, '#'), Some(10));
+        assert_eq!(maze.get_distance(begin, end), Some(10));
+    }
+
+    #[test]
+    fn test_distance_field() {
+        let data = "
+        #######
+        #B..#E#
+        #.#...#
+        #######
+        ";
+        let maze = Maze::load(data);
+        let field = maze.distance_field([(1, 1)]);
+        assert_eq!(field.get((5, 1)), Some(6));
+        assert_eq!(field.get((0, 0)), None);
+        let field = maze.distance_field([(1, 1), (5, 1)]);
+        assert_eq!(field.get((3, 2)), Some(3));
+        assert_eq!(field.get((4, 2)), Some(2));
+        let nearest = maze.nearest_sources([(1, 1), (5, 1)]);
+        assert_eq!(nearest.get((3, 1)), Some(Nearest {source: 0, distance: 2}));
+        assert_eq!(nearest.get((4, 2)), Some(Nearest {source: 1, distance: 2}));
+        assert_eq!(maze.distance_field([(0, 0)]).get((1, 1)), None);
+
+        let distances = maze.distances((1, 1), (5, 1));
+        assert_eq!(distances.get_distance(), Some(6));
+        assert_eq!(distances.through((3, 2)), Some(6));
+        assert_eq!(distances.through((1, 2)), Some(8));
+        assert_eq!(distances.through((4, 1)), None);
+    }
+
+    #[test]
+    fn test_passable() {
+        let data = "
+        #S.X#
+        #..~E
+        ";
+        let maze = Maze::load(data);
+        assert_eq!((maze.start(), maze.end()), (Some((1, 0)), Some((4, 1))));
+        assert_eq!(maze.get_distance((1, 0), (4, 1)), Some(4));
+        let maze = maze.with_walls("#X~");
+        assert!(!maze.is_passable((3, 0)) && maze.is_passable((1, 0)));
+        assert_eq!(maze.get_distance((1, 0), (4, 1)), None);
+        let maze = maze.with_passable(|c| c != '#' && c != 'X');
+        assert_eq!(maze.neighbors(Point::new(3, 1)).collect::<Vec<_>>(), vec![Point::new(4, 1), Point::new(2, 1)]);
+        assert_eq!(maze.get_distance((1, 0), (4, 1)), Some(4));
     }
 
     #[test]
@@ -162,7 +310,7 @@ mod tests {
         ";
         let maze = Maze::load(data);
         let map = &maze.get_map();
-        let mut path = maze.get_path(map.find('B').unwrap(), map.find('E').unwrap(), '#').unwrap();
+        let mut path = maze.get_path(map.find('B').unwrap(), map.find('E').unwrap()).unwrap();
         assert_eq!(path.next(), Some((1, 1)));
         assert_eq!(path.next(), Some((2, 1)));
         assert_eq!(path.next(), None);
@@ -0,0 +1,85 @@
+use std::str::FromStr;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: Option<u8>,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, expected: &str) -> Self {
+        Self {day: None, line, column, expected: expected.to_string()}
+    }
+
+    // locates `s`, a slice of `data`, or reports no location if it isn't one
+    pub fn at(data: &str, s: &str, expected: &str) -> Self {
+        let (b, p) = (data.as_ptr() as usize, s.as_ptr() as usize);
+        if (p < b) || (p > (b + data.len())) {
+            return Self::new(0, 0, expected);
+        }
+        let before = &data[..(p - b)];
+        let line = before.matches('\n').count() + 1;
+        let column = before[before.rfind('\n').map_or(0, |i| i + 1)..].chars().count() + 1;
+        Self::new(line, column, expected)
+    }
+
+    // locates the end of `s`, for something missing after it
+    pub fn after(data: &str, s: &str, expected: &str) -> Self {
+        Self::at(data, &s[s.len()..], expected)
+    }
+
+    pub fn with_day(self, day: u8) -> Self {
+        Self {day: Some(day), ..self}
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {day}, ")?;
+        }
+        if self.line > 0 {
+            write!(f, "line {}, column {}: ", self.line, self.column)?;
+        }
+        write!(f, "expected {}", self.expected)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub fn parse_at<T: FromStr>(data: &str, s: &str, expected: &str) -> Result<T, ParseError> {
+    s.parse().map_err(|_| ParseError::at(data, s, expected))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_at() {
+        let data = "1,2\n3,x\n";
+        let x = data.find('x').unwrap();
+        let e = ParseError::at(data, &data[x..(x + 1)], "a number");
+        assert_eq!((e.line, e.column), (2, 3));
+        assert_eq!(e.to_string(), "line 2, column 3: expected a number");
+        assert_eq!(e.with_day(7).to_string(), "day 7, line 2, column 3: expected a number");
+
+        let e = ParseError::after(data, &data[..3], "','");
+        assert_eq!((e.line, e.column), (1, 4));
+
+        let e = ParseError::at(data, "elsewhere", "a number");
+        assert_eq!((e.line, e.column), (0, 0));
+        assert_eq!(e.to_string(), "expected a number");
+    }
+
+    #[test]
+    fn test_parse_at() {
+        let data = "12 é3";
+        let n: Result<u32, _> = parse_at(data, &data[..2], "a number");
+        assert_eq!(n, Ok(12));
+        let n: Result<u32, _> = parse_at(data, &data[3..], "a number");
+        assert_eq!(n, Err(ParseError::new(1, 4, "a number")));
+    }
+}
@@ -0,0 +1,302 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::Hash;
+
+// what a search learnt about the states it reached
+#[derive(Debug)]
+pub struct Search<S> {
+    // the lowest cost to each settled state
+    pub costs: HashMap<S, u64>,
+    // the state before each reached state on a lowest cost path, none for the starts
+    pub predecessors: HashMap<S, S>,
+    // the first goal settled
+    pub goal: Option<S>
+}
+
+impl<S: Copy + Eq + Hash> Search<S> {
+    pub fn distance(&self) -> Option<u64> {
+        self.goal.and_then(|g| self.cost(g))
+    }
+
+    pub fn cost(&self, s: S) -> Option<u64> {
+        self.costs.get(&s).copied()
+    }
+
+    // from a start to the goal
+    pub fn path(&self) -> Option<Vec<S>> {
+        self.path_to(self.goal?)
+    }
+
+    pub fn path_to(&self, s: S) -> Option<Vec<S>> {
+        self.costs.get(&s)?;
+        let mut path = vec![s];
+        let mut s = s;
+        while let Some(p) = self.predecessors.get(&s) {
+            path.push(*p);
+            s = *p;
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+// a state in the queue, the lowest estimate first
+struct Entry<S> {
+    estimate: u64,
+    cost: u64,
+    state: S
+}
+
+impl<S> PartialEq for Entry<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<S> Eq for Entry<S> {}
+
+impl<S> PartialOrd for Entry<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S> Ord for Entry<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (other.estimate, other.cost).cmp(&(self.estimate, self.cost))
+    }
+}
+
+pub fn dijkstra<S, N, I, G>(starts: impl IntoIterator<Item = S>, neighbors: N, is_goal: G) -> Search<S>
+where
+    S: Copy + Eq + Hash,
+    N: FnMut(S) -> I,
+    I: IntoIterator<Item = (S, u64)>,
+    G: FnMut(S) -> bool
+{
+    astar(starts, neighbors, |_| 0, is_goal)
+}
+
+// neighbors gives the states next to a state with the cost of the move; a settled
+// state is never reopened, so the heuristic must be consistent, h(s) <= c(s, n) + h(n)
+// for every move, which manhattan distance is on a grid; with no goal everything
+// reachable is settled
+pub fn astar<S, N, I, H, G>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbors: N,
+    mut heuristic: H,
+    mut is_goal: G
+) -> Search<S>
+where
+    S: Copy + Eq + Hash,
+    N: FnMut(S) -> I,
+    I: IntoIterator<Item = (S, u64)>,
+    H: FnMut(S) -> u64,
+    G: FnMut(S) -> bool
+{
+    let mut costs = HashMap::new();
+    let mut predecessors = HashMap::new();
+    let mut best = HashMap::new();
+    let mut queue = BinaryHeap::new();
+    for s in starts {
+        best.insert(s, 0);
+        queue.push(Entry {estimate: heuristic(s), cost: 0, state: s});
+    }
+    while let Some(Entry {cost, state, ..}) = queue.pop() {
+        if costs.contains_key(&state) {continue;}
+        costs.insert(state, cost);
+        if is_goal(state) {
+            return Search {costs, predecessors, goal: Some(state)};
+        }
+        for (n, c) in neighbors(state) {
+            let nc = cost + c;
+            if costs.contains_key(&n) || best.get(&n).is_some_and(|&b| b <= nc) {continue;}
+            best.insert(n, nc);
+            predecessors.insert(n, state);
+            queue.push(Entry {estimate: nc + heuristic(n), cost: nc, state: n});
+        }
+    }
+    Search {costs, predecessors, goal: None}
+}
+
+// every lowest cost path to the goals, as a graph of predecessors
+#[derive(Debug)]
+pub struct AllPaths<S> {
+    // the lowest cost to each settled state
+    pub costs: HashMap<S, u64>,
+    // all the states before each reached state on a lowest cost path, none for the starts
+    pub predecessors: HashMap<S, Vec<S>>,
+    // the goals reached at the lowest cost
+    pub goals: Vec<S>
+}
+
+impl<S: Copy + Eq + Hash> AllPaths<S> {
+    pub fn distance(&self) -> Option<u64> {
+        self.goals.first().and_then(|g| self.costs.get(g).copied())
+    }
+
+    // the number of lowest cost paths to any goal
+    pub fn count(&self) -> u64 {
+        let mut states: Vec<_> = self.states().into_iter().collect();
+        states.sort_by_key(|s| self.costs[s]);
+        let mut counts = HashMap::new();
+        for s in states {
+            let n = match self.predecessors.get(&s) {
+                Some(ps) => ps.iter().map(|p| counts[p]).sum(),
+                None => 1
+            };
+            counts.insert(s, n);
+        }
+        self.goals.iter().map(|g| counts[g]).sum()
+    }
+
+    // the lowest cost paths from a start to a goal, one at a time
+    pub fn paths(&self) -> impl Iterator<Item = Vec<S>> + '_ {
+        let mut stack: Vec<Vec<S>> = self.goals.iter().map(|&g| vec![g]).collect();
+        std::iter::from_fn(move || {
+            while let Some(path) = stack.pop() {
+                let s = *path.last().unwrap();
+                match self.predecessors.get(&s) {
+                    Some(ps) => {
+                        for p in ps {
+                            let mut path = path.clone();
+                            path.push(*p);
+                            stack.push(path);
+                        }
+                    }
+                    None => {
+                        let mut path = path;
+                        path.reverse();
+                        return Some(path);
+                    }
+                }
+            }
+            None
+        })
+    }
+
+    // the states on any lowest cost path
+    pub fn states(&self) -> HashSet<S> {
+        let mut states: HashSet<_> = self.goals.iter().copied().collect();
+        let mut stack = self.goals.clone();
+        while let Some(s) = stack.pop() {
+            for p in self.predecessors.get(&s).into_iter().flatten() {
+                if states.insert(*p) {
+                    stack.push(*p);
+                }
+            }
+        }
+        states
+    }
+}
+
+// dijkstra keeping every lowest cost path, the costs of the moves must be positive
+pub fn dijkstra_all<S, N, I, G>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbors: N,
+    mut is_goal: G
+) -> AllPaths<S>
+where
+    S: Copy + Eq + Hash,
+    N: FnMut(S) -> I,
+    I: IntoIterator<Item = (S, u64)>,
+    G: FnMut(S) -> bool
+{
+    let mut costs = HashMap::new();
+    let mut predecessors: HashMap<S, Vec<S>> = HashMap::new();
+    let mut best = HashMap::new();
+    let mut queue = BinaryHeap::new();
+    let mut goals = Vec::new();
+    let mut distance = None;
+    for s in starts {
+        best.insert(s, 0);
+        queue.push(Entry {estimate: 0, cost: 0, state: s});
+    }
+    while let Some(Entry {cost, state, ..}) = queue.pop() {
+        if distance.is_some_and(|d| cost > d) {break;}
+        if costs.contains_key(&state) {continue;}
+        costs.insert(state, cost);
+        if is_goal(state) {
+            distance = Some(cost);
+            goals.push(state);
+            continue;
+        }
+        for (n, c) in neighbors(state) {
+            let nc = cost + c;
+            match best.get(&n) {
+                Some(&b) if b < nc => (),
+                Some(&b) if b == nc => predecessors.entry(n).or_default().push(state),
+                _ => {
+                    best.insert(n, nc);
+                    predecessors.insert(n, vec![state]);
+                    queue.push(Entry {estimate: nc, cost: nc, state: n});
+                }
+            }
+        }
+    }
+    AllPaths {costs, predecessors, goals}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc::grid::{Grid, Point};
+
+    #[test]
+    fn test_dijkstra() {
+        // 1 -3-> 2 -2-> 4, 1 -5-> 4, 1 -1-> 3 -1-> 2
+        let edges = [(1, 2, 3), (2, 4, 2), (1, 4, 5), (1, 3, 1), (3, 2, 1)];
+        let neighbors = |s| edges.iter().filter(move |e| e.0 == s).map(|e| (e.1, e.2));
+        let search = dijkstra([1], neighbors, |s| s == 4);
+        assert_eq!(search.distance(), Some(4));
+        assert_eq!(search.path(), Some(vec![1, 3, 2, 4]));
+        assert_eq!(search.cost(3), Some(1));
+
+        let search = dijkstra([1], neighbors, |_| false);
+        assert_eq!(search.goal, None);
+        assert_eq!(search.costs.len(), 4);
+        assert_eq!(search.path_to(2), Some(vec![1, 3, 2]));
+        assert_eq!(search.path_to(5), None);
+    }
+
+    #[test]
+    fn test_dijkstra_all() {
+        // two ways to 4 through 2 or 3, and 5 after 4 or straight from 1
+        let edges = [(1, 2, 1), (1, 3, 1), (2, 4, 1), (3, 4, 1), (4, 5, 1), (1, 5, 3), (1, 6, 1), (6, 5, 5)];
+        let neighbors = |s| edges.iter().filter(move |e| e.0 == s).map(|e| (e.1, e.2));
+        let paths = dijkstra_all([1], neighbors, |s| s == 5);
+        assert_eq!(paths.distance(), Some(3));
+        assert_eq!(paths.goals, vec![5]);
+        assert_eq!(paths.count(), 3);
+        let mut all: Vec<_> = paths.paths().collect();
+        all.sort();
+        assert_eq!(all, vec![vec![1, 2, 4, 5], vec![1, 3, 4, 5], vec![1, 5]]);
+        assert_eq!(paths.states(), HashSet::from([1, 2, 3, 4, 5]));
+
+        let paths = dijkstra_all([1], neighbors, |s| s == 7);
+        assert_eq!(paths.distance(), None);
+        assert_eq!(paths.count(), 0);
+        assert_eq!(paths.paths().count(), 0);
+    }
+
+    #[test]
+    fn test_astar() {
+        let data = "
+        .....
+        .###.
+        ...#.
+        ";
+        let grid: Grid<char> = Grid::load(data, "");
+        let (begin, end) = (Point::new(0, 2), Point::new(4, 2));
+        let neighbors = |p| grid.neighbors4(p).filter(|&(_, c)| c != '#').map(|(n, _)| (n, 1));
+        let search = astar([begin], neighbors, |p| p.manhattan(end) as u64, |p| p == end);
+        assert_eq!(search.distance(), Some(8));
+        let path = search.path().unwrap();
+        assert_eq!(path.len(), 9);
+        assert_eq!((path[0], path[8]), (begin, end));
+        assert!(path.windows(2).all(|w| w[0].manhattan(w[1]) == 1));
+
+        let search = astar([begin], neighbors, |p| p.manhattan(end) as u64, |p| p == Point::new(2, 1));
+        assert_eq!(search.distance(), None);
+    }
+}
@@ -0,0 +1,173 @@
+use std::any::Any;
+
+use crate::aoc::parse::ParseError;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String)
+}
+
+macro_rules! answer_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Number(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_number!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+impl std::fmt::Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}")
+        }
+    }
+}
+
+// an example of the puzzle text with its answers, the input being inline or in data/day_NN/<name>.txt
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Example {
+    pub name: &'static str,
+    pub input: Option<&'static str>,
+    pub answers: [Option<&'static str>; 2]
+}
+
+impl Example {
+    pub const fn new(name: &'static str, part_1: Option<&'static str>, part_2: Option<&'static str>) -> Self {
+        Self {name, input: None, answers: [part_1, part_2]}
+    }
+
+    pub const fn inline(name: &'static str, input: &'static str, part_1: Option<&'static str>, part_2: Option<&'static str>) -> Self {
+        Self {name, input: Some(input), answers: [part_1, part_2]}
+    }
+
+    pub fn get_answer(&self, part: u8) -> Option<&'static str> {
+        self.answers.get(usize::from(part).checked_sub(1)?).copied().flatten()
+    }
+}
+
+// the answer of an example of the day, for its unit tests
+#[cfg(test)]
+pub fn expected<S: Solution>(name: &str, part: u8) -> &'static str {
+    let example = S::EXAMPLES.iter().find(|e| e.name == name).expect("declared example");
+    example.get_answer(part).expect("declared answer")
+}
+
+pub trait Solution {
+    const DAY: u8;
+    const EXAMPLES: &'static [Example] = &[];
+
+    type Puzzle;
+    type Part1: Into<Answer>;
+    type Part2: Into<Answer>;
+
+    fn parse(data: &str) -> Result<Self::Puzzle, ParseError>;
+    fn part_1(puzzle: &Self::Puzzle) -> Self::Part1;
+    fn part_2(puzzle: &Self::Puzzle) -> Self::Part2;
+}
+
+// object safe view of a Solution, the parsed puzzle being type erased
+pub trait Solver: Sync {
+    fn day(&self) -> u8;
+    fn examples(&self) -> &'static [Example];
+    fn parse(&self, data: &str) -> Result<Box<dyn Any + Send + Sync>, ParseError>;
+    fn part_1(&self, puzzle: &dyn Any) -> Answer;
+    fn part_2(&self, puzzle: &dyn Any) -> Answer;
+}
+
+// puzzles are shared with the threads running the parts
+impl<S: Solution + Sync> Solver for S where S::Puzzle: Send + Sync + 'static {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        S::EXAMPLES
+    }
+
+    fn parse(&self, data: &str) -> Result<Box<dyn Any + Send + Sync>, ParseError> {
+        match S::parse(data) {
+            Ok(puzzle) => Ok(Box::new(puzzle)),
+            Err(e) => Err(e.with_day(S::DAY))
+        }
+    }
+
+    fn part_1(&self, puzzle: &dyn Any) -> Answer {
+        let puzzle = puzzle.downcast_ref().expect("puzzle of the same day");
+        S::part_1(puzzle).into()
+    }
+
+    fn part_2(&self, puzzle: &dyn Any) -> Answer {
+        let puzzle = puzzle.downcast_ref().expect("puzzle of the same day");
+        S::part_2(puzzle).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc::parse::parse_at;
+
+    struct Test;
+
+    impl Solution for Test {
+        const DAY: u8 = 0;
+        const EXAMPLES: &'static [Example] = &[Example::inline("test", "1,2,3", Some("6"), Some("321"))];
+
+        type Puzzle = Vec<u32>;
+        type Part1 = u32;
+        type Part2 = String;
+
+        fn parse(data: &str) -> Result<Vec<u32>, ParseError> {
+            data.split(',').map(|s| parse_at(data, s, "a number")).collect()
+        }
+
+        fn part_1(puzzle: &Vec<u32>) -> u32 {
+            puzzle.iter().sum()
+        }
+
+        fn part_2(puzzle: &Vec<u32>) -> String {
+            puzzle.iter().rev().map(|n| n.to_string()).collect()
+        }
+    }
+
+    #[test]
+    fn test_answer() {
+        assert_eq!(Answer::from(42u8), Answer::Number(42));
+        assert_eq!(Answer::from(-1i64).to_string(), "-1");
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(Answer::from("a,b").to_string(), "a,b");
+    }
+
+    #[test]
+    fn test_solver() {
+        let solver: &dyn Solver = &Test;
+        assert_eq!(solver.day(), 0);
+        assert_eq!(solver.examples()[0].get_answer(1), Some("6"));
+        assert_eq!(solver.examples()[0].get_answer(3), None);
+        assert_eq!(solver.parse("1,x").err(), Some(ParseError::new(1, 3, "a number").with_day(0)));
+        let puzzle = solver.parse("1,2,3").unwrap();
+        assert_eq!(solver.part_1(&*puzzle), Answer::Number(6));
+        assert_eq!(solver.part_2(&*puzzle), Answer::Text("321".to_string()));
+        assert_eq!(expected::<Test>("test", 2), "321");
+    }
+}
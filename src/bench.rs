@@ -0,0 +1,218 @@
+use std::hint::black_box;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use aoc_2024::aoc::parse::ParseError;
+use aoc_2024::aoc::solution::Solver;
+use crate::report::format_duration;
+use crate::runner::{catch, quiet, Status};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration
+}
+
+impl Stats {
+    pub fn new(samples: &[Duration]) -> Self {
+        let mut samples = samples.to_vec();
+        samples.sort();
+        let runs = samples.len();
+        let median = if runs % 2 == 1 {
+            samples[runs / 2]
+        } else {
+            (samples[runs / 2 - 1] + samples[runs / 2]) / 2
+        };
+        let ns: Vec<_> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        let mean = ns.iter().sum::<f64>() / runs as f64;
+        let variance = ns.iter().map(|n| (n - mean) * (n - mean)).sum::<f64>() / runs as f64;
+        Self {
+            runs,
+            min: samples[0],
+            median,
+            mean: Duration::from_nanos(mean as u64),
+            stddev: Duration::from_nanos(variance.sqrt() as u64)
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Timing {
+    // "parse", "part 1" or "part 2"
+    pub name: String,
+    pub stats: Result<Stats, String>
+}
+
+#[derive(Clone, Debug)]
+pub struct BenchResult {
+    pub day: u8,
+    pub error: Option<String>,
+    pub timings: Vec<Timing>
+}
+
+impl BenchResult {
+    pub fn failed(day: u8, error: String) -> Self {
+        Self {day, error: Some(error), timings: Vec::new()}
+    }
+}
+
+fn measure<R, F: FnMut() -> R>(warmup: usize, runs: usize, mut f: F) -> Stats {
+    for _ in 0..warmup {
+        black_box(f());
+    }
+    let samples: Vec<_> = (0..runs).map(|_| {
+        let start = Instant::now();
+        black_box(f());
+        start.elapsed()
+    }).collect();
+    Stats::new(&samples)
+}
+
+pub fn bench_day(solver: &dyn Solver, data: &str, parts: &[u8], warmup: usize, runs: usize) -> BenchResult {
+    let day = solver.day();
+    let puzzle = match catch(|| solver.parse(data)).0 {
+        Ok(Ok(puzzle)) => puzzle,
+        Ok(Err(e)) => return BenchResult::failed(day, format!("invalid input, {}", ParseError {day: None, ..e})),
+        Err(e) => return BenchResult::failed(day, format!("parse panicked: {e}"))
+    };
+    let puzzle = &*puzzle;
+    let stats = catch(|| measure(warmup, runs, || solver.parse(data))).0;
+    let mut timings = vec![Timing {name: "parse".to_string(), stats}];
+    for &part in parts {
+        let stats = catch(|| measure(warmup, runs, || {
+            if part == 1 {solver.part_1(puzzle)} else {solver.part_2(puzzle)}
+        })).0;
+        let stats = stats.map_err(|e| match Status::from_panic(e) {
+            Status::Panic(e) => format!("panicked: {e}"),
+            _ => "unimplemented".to_string()
+        });
+        timings.push(Timing {name: format!("part {part}"), stats});
+    }
+    BenchResult {day, error: None, timings}
+}
+
+pub fn bench_days<F>(days: &[u8], parts: &[u8], warmup: usize, runs: usize, mut load: F) -> Vec<BenchResult>
+where F: FnMut(u8) -> Result<(&'static dyn Solver, String), String>
+{
+    quiet(|| days.iter().map(|&day| {
+        match load(day) {
+            Ok((solver, data)) => bench_day(solver, &data, parts, warmup, runs),
+            Err(e) => BenchResult::failed(day, e)
+        }
+    }).collect())
+}
+
+// one "<day> <name>: <median in ns>" line per timing
+pub type Baseline = Vec<(u8, String, Duration)>;
+
+pub fn parse_baseline(data: &str) -> Option<Baseline> {
+    data.lines().map(|s| s.trim()).filter(|s| !s.is_empty()).map(|s| {
+        let (key, ns) = s.rsplit_once(':')?;
+        let (day, name) = key.trim().split_once(' ')?;
+        Some((day.parse().ok()?, name.trim().to_string(), Duration::from_nanos(ns.trim().parse().ok()?)))
+    }).collect()
+}
+
+pub fn get_baseline(results: &[BenchResult]) -> String {
+    results.iter().flat_map(|r| r.timings.iter().filter_map(|t| {
+        t.stats.as_ref().ok().map(|s| format!("{} {}: {}\n", r.day, t.name, s.median.as_nanos()))
+    })).collect()
+}
+
+pub fn load_baseline(path: &Path) -> Result<Baseline, String> {
+    let data = std::fs::read_to_string(path).map_err(|e| format!("cannot read '{}': {e}", path.display()))?;
+    parse_baseline(&data).ok_or_else(|| format!("invalid baseline in '{}'", path.display()))
+}
+
+pub fn save_baseline(path: &Path, results: &[BenchResult]) -> Result<(), String> {
+    std::fs::write(path, get_baseline(results)).map_err(|e| format!("cannot write '{}': {e}", path.display()))
+}
+
+// relative change of the median against the baseline, if there is one
+pub fn get_change(baseline: &Baseline, day: u8, timing: &Timing) -> Option<f64> {
+    let stats = timing.stats.as_ref().ok()?;
+    let (_, _, base) = baseline.iter().find(|(d, name, _)| (*d == day) && (*name == timing.name))?;
+    Some((stats.median.as_nanos() as f64 / base.as_nanos().max(1) as f64) - 1.0)
+}
+
+pub fn count_regressions(results: &[BenchResult], baseline: &Baseline, threshold: f64) -> usize {
+    results.iter().flat_map(|r| r.timings.iter().map(move |t| get_change(baseline, r.day, t)))
+        .filter(|c| c.is_some_and(|c| c > threshold))
+        .count()
+}
+
+pub fn print_bench(results: &[BenchResult], baseline: Option<&Baseline>, threshold: f64) {
+    let rows: Vec<_> = results.iter().flat_map(|r| {
+        let error = r.error.iter().map(|e| (r.day, String::new(), Err(format!("error: {e}")), String::new()));
+        let timings = r.timings.iter().map(|t| {
+            let stats = t.stats.as_ref().map(|s| [s.min, s.median, s.mean, s.stddev].map(format_duration));
+            let change = match baseline.and_then(|b| get_change(b, r.day, t)) {
+                Some(c) if c > threshold => format!("{:+.1}% REGRESSION", c * 100.0),
+                Some(c) => format!("{:+.1}%", c * 100.0),
+                None => String::new()
+            };
+            (r.day, t.name.clone(), stats.map_err(|e| e.clone()), change)
+        });
+        error.chain(timings).collect::<Vec<_>>()
+    }).collect();
+    let width = rows.iter().filter_map(|r| r.2.as_ref().ok()).flatten().map(|s| s.chars().count()).max().unwrap_or(0);
+    let width = width.max("stddev".len());
+    let header = format!("day  {:6}  {:>width$}  {:>width$}  {:>width$}  {:>width$}", "", "min", "median", "mean", "stddev");
+    println!("{}", if baseline.is_some() {header + "  change"} else {header});
+    for (day, name, stats, change) in rows {
+        match stats {
+            Ok([min, median, mean, stddev]) => {
+                let line = format!("{day:>3}  {name:6}  {min:>width$}  {median:>width$}  {mean:>width$}  {stddev:>width$}");
+                if change.is_empty() {println!("{line}")} else {println!("{line}  {change}")}
+            }
+            Err(e) => println!("{day:>3}  {name:6}  {e}")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::new(&[ms(4), ms(2), ms(6), ms(8)]);
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min, ms(2));
+        assert_eq!(stats.median, ms(5));
+        assert_eq!(stats.mean, ms(5));
+        assert_eq!(stats.stddev, Duration::from_nanos(2_236_067));
+        assert_eq!(Stats::new(&[ms(3), ms(1), ms(2)]).median, ms(2));
+    }
+
+    #[test]
+    fn test_baseline() {
+        let results = vec![
+            BenchResult {day: 5, error: None, timings: vec![
+                Timing {name: "parse".to_string(), stats: Ok(Stats::new(&[ms(1)]))},
+                Timing {name: "part 1".to_string(), stats: Ok(Stats::new(&[ms(12)]))},
+                Timing {name: "part 2".to_string(), stats: Err("unimplemented".to_string())}
+            ]},
+            BenchResult::failed(6, "no input".to_string())
+        ];
+        let data = get_baseline(&results);
+        assert_eq!(data, "5 parse: 1000000\n5 part 1: 12000000\n");
+        let baseline = parse_baseline(&data).unwrap();
+        assert_eq!(baseline[1], (5, "part 1".to_string(), ms(12)));
+        assert_eq!(parse_baseline("5 part 1 12"), None);
+
+        let baseline = parse_baseline("5 parse: 1000000\n5 part 1: 10000000\n").unwrap();
+        assert_eq!(get_change(&baseline, 5, &results[0].timings[0]), Some(0.0));
+        assert!((get_change(&baseline, 5, &results[0].timings[1]).unwrap() - 0.2).abs() < 1e-9);
+        assert_eq!(get_change(&baseline, 5, &results[0].timings[2]), None);
+        assert_eq!(count_regressions(&results, &baseline, 0.1), 1);
+        assert_eq!(count_regressions(&results, &baseline, 0.25), 0);
+    }
+}
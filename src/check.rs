@@ -0,0 +1,111 @@
+use std::path::{Path, PathBuf};
+
+use crate::input::get_day_dir;
+use crate::runner::{DayResult, Status};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Check {
+    Pass,
+    Missing,
+    Fail
+}
+
+impl std::fmt::Display for Check {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Check::Pass => write!(f, "PASS"),
+            Check::Missing => write!(f, "MISSING"),
+            Check::Fail => write!(f, "FAIL")
+        }
+    }
+}
+
+pub fn get_answers_path(root: &Path, day: u8) -> PathBuf {
+    get_day_dir(root, day).join("answers.txt")
+}
+
+// same format as the single day output, so that it can be recorded with `aoc-2024 <day> > answers.txt`
+pub fn parse_answers(data: &str) -> Option<Vec<(u8, String)>> {
+    data.lines().map(|s| s.trim()).filter(|s| !s.is_empty()).map(|s| {
+        let (part, answer) = s.split_once(':')?;
+        let part = part.trim().strip_prefix("part")?.trim().parse().ok()?;
+        Some((part, answer.trim().to_string()))
+    }).collect()
+}
+
+pub fn load_answers(root: &Path, day: u8) -> Result<Vec<(u8, String)>, String> {
+    let path = get_answers_path(root, day);
+    match std::fs::read_to_string(&path) {
+        Ok(data) => parse_answers(&data).ok_or_else(|| format!("invalid answers in '{}'", path.display())),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(format!("cannot read '{}': {e}", path.display()))
+    }
+}
+
+pub fn check_day(result: &mut DayResult, answers: &[(u8, String)]) {
+    for p in result.parts.iter_mut() {
+        let expected = answers.iter().find(|(part, _)| *part == p.part).map(|(_, a)| a.clone());
+        p.check = Some(match (&p.status, &expected) {
+            (_, None) => Check::Missing,
+            (Status::Ok(answer), Some(expected)) if answer.to_string() == *expected => Check::Pass,
+            _ => Check::Fail
+        });
+        p.expected = expected;
+    }
+}
+
+impl DayResult {
+    pub fn get_check(&self) -> Option<Check> {
+        if self.error.is_some() {
+            return Some(Check::Fail);
+        }
+        self.parts.iter().filter_map(|p| p.check).max()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_2024::aoc::solution::Answer;
+    use crate::runner::PartResult;
+    use std::time::Duration;
+
+    fn part(part: u8, status: Status) -> PartResult {
+        PartResult::new(part, status)
+    }
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = parse_answers("part 1: 6951\npart 2: co,de,ka,ta\n\n").unwrap();
+        assert_eq!(answers, vec![(1, "6951".to_string()), (2, "co,de,ka,ta".to_string())]);
+        assert_eq!(parse_answers(""), Some(Vec::new()));
+        assert_eq!(parse_answers("6951"), None);
+        assert_eq!(parse_answers("part x: 6951"), None);
+    }
+
+    #[test]
+    fn test_check_day() {
+        let mut result = DayResult {
+            day: 5,
+            error: None,
+            parse: Duration::ZERO,
+            parts: vec![
+                part(1, Status::Ok(Answer::Number(6951))),
+                part(2, Status::Ok(Answer::Number(4120)))
+            ]
+        };
+        check_day(&mut result, &[(1, "6951".to_string()), (2, "4121".to_string())]);
+        assert_eq!(result.parts[0].check, Some(Check::Pass));
+        assert_eq!(result.parts[1].check, Some(Check::Fail));
+        assert_eq!(result.parts[1].expected, Some("4121".to_string()));
+        assert_eq!(result.get_check(), Some(Check::Fail));
+
+        check_day(&mut result, &[(1, "6951".to_string())]);
+        assert_eq!(result.parts[1].check, Some(Check::Missing));
+        assert_eq!(result.get_check(), Some(Check::Missing));
+
+        result.parts[1].status = Status::Unimplemented;
+        check_day(&mut result, &[(1, "6951".to_string()), (2, "4121".to_string())]);
+        assert_eq!(result.parts[1].check, Some(Check::Fail));
+    }
+}
@@ -0,0 +1,127 @@
+use std::path::{Path, PathBuf};
+
+use crate::http;
+use crate::input::{find_input, get_day_dir};
+
+pub const URL_ENV: &str = "AOC_URL";
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+    // there is no default, the site needing a local http proxy
+    pub url: Option<String>,
+    pub session: Option<String>
+}
+
+pub fn get_config_path(root: &Path) -> PathBuf {
+    root.join("config.txt")
+}
+
+// "key = value" lines, '#' starting a comment
+pub fn parse_config(data: &str) -> Result<Vec<(String, String)>, String> {
+    data.lines().enumerate().map(|(i, s)| (i, s.trim())).filter(|(_, s)| !s.is_empty() && !s.starts_with('#')).map(|(i, s)| {
+        let (key, value) = s.split_once('=').ok_or_else(|| format!("line {}: expected 'key = value'", i + 1))?;
+        Ok((key.trim().to_string(), value.trim().to_string()))
+    }).collect()
+}
+
+fn get_env(name: &str) -> Option<String> {
+    std::env::var(name).ok().filter(|s| !s.is_empty())
+}
+
+// the environment overrides <data>/config.txt
+pub fn load_config(root: &Path) -> Result<Config, String> {
+    let path = get_config_path(root);
+    let entries = match std::fs::read_to_string(&path) {
+        Ok(data) => parse_config(&data).map_err(|e| format!("invalid '{}': {e}", path.display()))?,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+        Err(e) => return Err(format!("cannot read '{}': {e}", path.display()))
+    };
+    let get = |key: &str| entries.iter().find(|(k, _)| k == key).map(|(_, v)| v.clone());
+    Ok(Config {
+        url: get_env(URL_ENV).or_else(|| get("url")),
+        session: get_env(SESSION_ENV).or_else(|| get("session"))
+    })
+}
+
+impl Config {
+    pub fn get_url(&self, path: &str) -> Result<String, String> {
+        let url = self.url.as_ref().ok_or_else(|| format!("no site URL, set ${URL_ENV} or 'url' in <data>/config.txt to an http:// URL (e.g. a local TLS proxy)"))?;
+        Ok(format!("{}{path}", url.trim_end_matches('/')))
+    }
+
+    pub fn get_cookie(&self) -> Result<String, String> {
+        let session = self.session.as_ref().ok_or_else(|| format!("no session token, set ${SESSION_ENV} or 'session' in <data>/config.txt"))?;
+        Ok(format!("session={session}"))
+    }
+}
+
+// downloads the input of the day unless it is already under the data root, returning its path and whether it was fetched
+pub fn fetch_input(config: &Config, root: &Path, day: u8) -> Result<(PathBuf, bool), String> {
+    if let Some(path) = find_input(root, day) {
+        return Ok((path, false));
+    }
+    let url = config.get_url(&format!("/day/{day}/input"))?;
+    let cookie = config.get_cookie()?;
+    let response = http::request("GET", &url, &[("Cookie", &cookie)], "")?;
+    if response.status != 200 {
+        let message = response.body.lines().next().unwrap_or("").trim().to_string();
+        return Err(format!("cannot fetch the input: HTTP {} {message}", response.status));
+    }
+    let dir = get_day_dir(root, day);
+    std::fs::create_dir_all(&dir).map_err(|e| format!("cannot create '{}': {e}", dir.display()))?;
+    let path = dir.join("input.txt");
+    std::fs::write(&path, &response.body).map_err(|e| format!("cannot write '{}': {e}", path.display()))?;
+    Ok((path, true))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::mock;
+    use crate::input::temp_root;
+
+    #[test]
+    fn test_parse_config() {
+        let entries = parse_config("# aoc\nsession = 53616c\n\nurl=http://localhost:8080/2024\n").unwrap();
+        assert_eq!(entries, vec![
+            ("session".to_string(), "53616c".to_string()),
+            ("url".to_string(), "http://localhost:8080/2024".to_string())
+        ]);
+        assert_eq!(parse_config("session\n"), Err("line 1: expected 'key = value'".to_string()));
+    }
+
+    #[test]
+    fn test_config() {
+        let config = Config {url: Some("http://localhost/2024/".to_string()), session: None};
+        assert_eq!(config.get_url("/day/1/input"), Ok("http://localhost/2024/day/1/input".to_string()));
+        assert!(config.get_cookie().is_err());
+        let config = Config {url: None, session: Some("53616c".to_string())};
+        assert!(config.get_url("/day/1/input").unwrap_err().starts_with("no site URL"));
+    }
+
+    #[test]
+    fn test_fetch_input() {
+        let root = temp_root("fetch");
+        let (url, server) = mock::serve(vec![mock::response(200, "3   4\n"), mock::response(404, "Not Found\n")]);
+        let config = Config {url: Some(format!("{url}/2024")), session: Some("53616c".to_string())};
+
+        // the empty placeholder of 'new' is fetched over
+        let path = get_day_dir(&root, 1).join("input.txt");
+        std::fs::create_dir_all(get_day_dir(&root, 1)).unwrap();
+        std::fs::write(&path, "").unwrap();
+        assert_eq!(fetch_input(&config, &root, 1), Ok((path.clone(), true)));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "3   4\n");
+        // cached, without any request
+        assert_eq!(fetch_input(&config, &root, 1), Ok((path, false)));
+
+        assert_eq!(fetch_input(&config, &root, 2), Err("cannot fetch the input: HTTP 404 Not Found".to_string()));
+        assert_eq!(find_input(&root, 2), None);
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2024/day/1/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("\r\nCookie: session=53616c\r\n"));
+        assert!(requests[1].starts_with("GET /2024/day/2/input HTTP/1.1\r\n"));
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
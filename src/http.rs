@@ -0,0 +1,203 @@
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::time::Duration;
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+const USER_AGENT: &str = "aoc-2024-rust";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Url {
+    pub host: String,
+    pub port: u16,
+    pub path: String
+}
+
+impl Url {
+    // std has no TLS, an https site needs a local proxy
+    pub fn parse(url: &str) -> Result<Self, String> {
+        if url.starts_with("https://") {
+            return Err(format!("cannot open '{url}': https is not supported, use an http:// URL (e.g. a local TLS proxy)"));
+        }
+        let rest = url.strip_prefix("http://").ok_or_else(|| format!("invalid URL '{url}'"))?;
+        let (authority, path) = match rest.find('/') {
+            Some(i) => (&rest[..i], &rest[i..]),
+            None => (rest, "/")
+        };
+        let (host, port) = match authority.rsplit_once(':') {
+            Some((host, port)) => (host, port.parse().map_err(|_| format!("invalid port in '{url}'"))?),
+            None => (authority, 80)
+        };
+        if host.is_empty() {
+            return Err(format!("invalid URL '{url}'"));
+        }
+        Ok(Self {host: host.to_string(), port, path: path.to_string()})
+    }
+
+    // the host for the Host header, with the port unless it is the default
+    pub fn authority(&self) -> String {
+        match self.port {
+            80 => self.host.clone(),
+            port => format!("{}:{port}", self.host)
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String
+}
+
+fn find(data: &[u8], s: &[u8]) -> Option<usize> {
+    data.windows(s.len()).position(|w| w == s)
+}
+
+fn dechunk(mut data: &[u8]) -> Result<Vec<u8>, String> {
+    let mut body = Vec::new();
+    loop {
+        let end = find(data, b"\r\n").ok_or("truncated chunk")?;
+        let size = String::from_utf8_lossy(&data[..end]);
+        let size = size.split(';').next().unwrap_or("").trim();
+        let size = usize::from_str_radix(size, 16).map_err(|_| format!("invalid chunk size '{size}'"))?;
+        if size == 0 {
+            return Ok(body);
+        }
+        data = &data[(end + 2)..];
+        if data.len() < size {
+            return Err("truncated chunk".to_string());
+        }
+        body.extend_from_slice(&data[..size]);
+        data = data[size..].strip_prefix(b"\r\n").ok_or("truncated chunk")?;
+    }
+}
+
+pub fn parse_response(data: &[u8]) -> Result<Response, String> {
+    let end = find(data, b"\r\n\r\n").ok_or("truncated response")?;
+    let head = String::from_utf8_lossy(&data[..end]);
+    let mut lines = head.lines();
+    let status = lines.next().and_then(|s| s.split_whitespace().nth(1)).and_then(|s| s.parse().ok());
+    let status = status.ok_or("invalid status line")?;
+    let headers: Vec<_> = lines.filter_map(|s| s.split_once(':')).map(|(k, v)| (k.trim().to_ascii_lowercase(), v.trim())).collect();
+    let header = |name: &str| headers.iter().find(|(k, _)| k == name).map(|(_, v)| *v);
+
+    let mut body = data[(end + 4)..].to_vec();
+    if header("transfer-encoding").is_some_and(|v| v.eq_ignore_ascii_case("chunked")) {
+        body = dechunk(&body)?;
+    } else if let Some(length) = header("content-length").and_then(|v| v.parse().ok()) {
+        body.truncate(length);
+    }
+    Ok(Response {status, body: String::from_utf8_lossy(&body).into_owned()})
+}
+
+pub fn request(method: &str, url: &str, headers: &[(&str, &str)], body: &str) -> Result<Response, String> {
+    let u = Url::parse(url)?;
+    let mut stream = TcpStream::connect((u.host.as_str(), u.port)).map_err(|e| format!("cannot connect to '{url}': {e}"))?;
+    stream.set_read_timeout(Some(TIMEOUT)).map_err(|e| e.to_string())?;
+    stream.set_write_timeout(Some(TIMEOUT)).map_err(|e| e.to_string())?;
+
+    let mut request = format!("{method} {} HTTP/1.1\r\nHost: {}\r\nUser-Agent: {USER_AGENT}\r\nConnection: close\r\n", u.path, u.authority());
+    for (name, value) in headers {
+        request += &format!("{name}: {value}\r\n");
+    }
+    if method != "GET" {
+        request += &format!("Content-Length: {}\r\n", body.len());
+    }
+    request += "\r\n";
+    request += body;
+    stream.write_all(request.as_bytes()).map_err(|e| format!("cannot send to '{url}': {e}"))?;
+
+    let mut data = Vec::new();
+    stream.read_to_end(&mut data).map_err(|e| format!("cannot read from '{url}': {e}"))?;
+    parse_response(&data)
+}
+
+// a local stand-in server for the tests
+#[cfg(test)]
+pub mod mock {
+    use std::io::{Read, Write};
+    use std::net::{TcpListener, TcpStream};
+    use std::thread::{self, JoinHandle};
+
+    use super::find;
+
+    pub fn response(status: u16, body: &str) -> String {
+        format!("HTTP/1.1 {status} OK\r\nContent-Length: {}\r\n\r\n{body}", body.len())
+    }
+
+    fn read_request(stream: &mut TcpStream) -> String {
+        let mut data = Vec::new();
+        let mut buffer = [0; 1024];
+        loop {
+            if let Some(end) = find(&data, b"\r\n\r\n") {
+                let head = String::from_utf8_lossy(&data[..end]).to_ascii_lowercase();
+                let length = head.lines().find_map(|s| s.strip_prefix("content-length:")).map_or(0, |s| s.trim().parse().unwrap());
+                if data.len() >= end + 4 + length {
+                    return String::from_utf8_lossy(&data).into_owned();
+                }
+            }
+            let n = stream.read(&mut buffer).unwrap();
+            if n == 0 {
+                return String::from_utf8_lossy(&data).into_owned();
+            }
+            data.extend_from_slice(&buffer[..n]);
+        }
+    }
+
+    // serves the responses in order, one per connection, and returns the requests
+    pub fn serve(responses: Vec<String>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || responses.into_iter().map(|response| {
+            let (mut stream, _) = listener.accept().unwrap();
+            let request = read_request(&mut stream);
+            stream.write_all(response.as_bytes()).unwrap();
+            request
+        }).collect());
+        (url, handle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_url() {
+        let url = Url::parse("http://localhost:8080/2024/day/1").unwrap();
+        assert_eq!(url, Url {host: "localhost".to_string(), port: 8080, path: "/2024/day/1".to_string()});
+        assert_eq!(Url::parse("http://example.com").unwrap().path, "/");
+        assert_eq!(Url::parse("http://example.com").unwrap().port, 80);
+        assert!(Url::parse("https://example.com").is_err());
+        assert!(Url::parse("example.com").is_err());
+        assert!(Url::parse("http://example.com:x/").is_err());
+        assert_eq!(url.authority(), "localhost:8080");
+        assert_eq!(Url::parse("http://example.com/").unwrap().authority(), "example.com");
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let r = parse_response(b"HTTP/1.1 200 OK\r\nContent-Length: 3\r\n\r\n1 2").unwrap();
+        assert_eq!(r, Response {status: 200, body: "1 2".to_string()});
+        let r = parse_response(b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n2\r\n1 \r\n1;x\r\n2\r\n0\r\n\r\n").unwrap();
+        assert_eq!(r.body, "1 2");
+        let r = parse_response(b"HTTP/1.1 404 Not Found\r\n\r\nmissing").unwrap();
+        assert_eq!(r, Response {status: 404, body: "missing".to_string()});
+        assert!(parse_response(b"HTTP/1.1 200 OK\r\n").is_err());
+        assert!(parse_response(b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n5\r\n1").is_err());
+    }
+
+    #[test]
+    fn test_request() {
+        let (url, server) = mock::serve(vec![mock::response(200, "ok"), mock::response(400, "bad")]);
+        let r = request("GET", &format!("{url}/a"), &[("Cookie", "session=s")], "").unwrap();
+        assert_eq!(r, Response {status: 200, body: "ok".to_string()});
+        let r = request("POST", &format!("{url}/b"), &[], "level=1").unwrap();
+        assert_eq!(r.status, 400);
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /a HTTP/1.1\r\n"));
+        assert!(requests[0].contains(&format!("\r\nHost: {}\r\n", url.trim_start_matches("http://"))));
+        assert!(requests[0].contains("\r\nCookie: session=s\r\n"));
+        assert!(requests[1].starts_with("POST /b HTTP/1.1\r\n"));
+        assert!(requests[1].ends_with("\r\n\r\nlevel=1"));
+    }
+}
@@ -0,0 +1,123 @@
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use aoc_2024::aoc::solution::Example;
+
+pub const DATA_ENV: &str = "AOC_DATA";
+pub const DATA_DIR: &str = "data";
+
+pub fn get_data_root(root: Option<&str>) -> PathBuf {
+    match root {
+        Some(root) => PathBuf::from(root),
+        None => match std::env::var(DATA_ENV) {
+            Ok(root) if !root.is_empty() => PathBuf::from(root),
+            _ => PathBuf::from(DATA_DIR)
+        }
+    }
+}
+
+pub fn get_day_dir(root: &Path, day: u8) -> PathBuf {
+    root.join(format!("day_{day:02}"))
+}
+
+// an empty file, like the placeholder of 'new', is no input
+pub fn find_input(root: &Path, day: u8) -> Option<PathBuf> {
+    [
+        get_day_dir(root, day).join("input.txt"),
+        root.join(format!("day_{day:02}.txt"))
+    ].into_iter().find(|p| std::fs::metadata(p).is_ok_and(|m| m.is_file() && (m.len() > 0)))
+}
+
+pub fn read_input(source: Option<&str>, root: &Path, day: u8) -> std::io::Result<String> {
+    match source {
+        Some("-") => {
+            let mut data = String::new();
+            std::io::stdin().read_to_string(&mut data)?;
+            Ok(data)
+        }
+        Some(path) => std::fs::read_to_string(path),
+        None => {
+            let path = find_input(root, day).ok_or_else(|| {
+                let message = format!("no input for day {day} under '{}'", root.display());
+                std::io::Error::new(std::io::ErrorKind::NotFound, message)
+            })?;
+            std::fs::read_to_string(path)
+        }
+    }
+}
+
+// inline, or else data/day_NN/<name>.txt
+pub fn read_example(root: &Path, day: u8, example: &Example) -> std::io::Result<String> {
+    match example.input {
+        Some(input) => Ok(input.to_string()),
+        None => std::fs::read_to_string(get_day_dir(root, day).join(format!("{}.txt", example.name)))
+    }
+}
+
+// an empty data root under the temporary directory, for the tests of each module
+#[cfg(test)]
+pub fn temp_root(name: &str) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("aoc-2024-{name}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&root);
+    std::fs::create_dir_all(&root).unwrap();
+    root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_data_root() {
+        assert_eq!(get_data_root(Some("inputs")), PathBuf::from("inputs"));
+    }
+
+    #[test]
+    fn test_find_input() {
+        let root = temp_root("find");
+        assert_eq!(find_input(&root, 1), None);
+
+        std::fs::write(root.join("day_02.txt"), "2").unwrap();
+        assert_eq!(find_input(&root, 2), Some(root.join("day_02.txt")));
+
+        std::fs::create_dir_all(get_day_dir(&root, 2)).unwrap();
+        std::fs::write(get_day_dir(&root, 2).join("input.txt"), "").unwrap();
+        assert_eq!(find_input(&root, 2), Some(root.join("day_02.txt")));
+        std::fs::write(get_day_dir(&root, 2).join("input.txt"), "2").unwrap();
+        assert_eq!(find_input(&root, 2), Some(root.join("day_02").join("input.txt")));
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_read_input() {
+        let root = temp_root("read");
+        assert!(read_input(None, &root, 3).is_err());
+        std::fs::write(root.join("day_03.txt"), "").unwrap();
+        assert_eq!(read_input(None, &root, 3).unwrap_err().kind(), std::io::ErrorKind::NotFound);
+
+        std::fs::write(root.join("day_03.txt"), "mul(2,4)").unwrap();
+        assert_eq!(read_input(None, &root, 3).unwrap(), "mul(2,4)");
+
+        let path = root.join("other.txt");
+        std::fs::write(&path, "other").unwrap();
+        assert_eq!(read_input(path.to_str(), &root, 3).unwrap(), "other");
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_read_example() {
+        let root = temp_root("example");
+        let example = Example::new("test_1", Some("2028"), None);
+        assert!(read_example(&root, 15, &example).is_err());
+
+        std::fs::create_dir_all(get_day_dir(&root, 15)).unwrap();
+        std::fs::write(get_day_dir(&root, 15).join("test_1.txt"), "#@#").unwrap();
+        assert_eq!(read_example(&root, 15, &example).unwrap(), "#@#");
+        let example = Example::inline("test", "mul(2,4)", Some("8"), None);
+        assert_eq!(read_example(&root, 15, &example).unwrap(), "mul(2,4)");
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,3 @@
+pub mod aoc;
+
+pub use aoc::{cancel, grid, maze, parse, search, sep, solution};
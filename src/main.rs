@@ -1,51 +1,404 @@
-mod aoc;
+mod bench;
+mod check;
+mod client;
+mod http;
+mod input;
+mod report;
+mod runner;
+mod scaffold;
+mod submit;
+mod watch;
+
+use aoc_2024::aoc;
 
 fn usage(exe: &str) -> ! {
     use std::path::Path;
     let exe = Path::new(exe).file_name().unwrap().to_str().unwrap();
-    println!("usage: {exe} <day (1-25)>");
+    println!("usage: {exe} [--data <dir>] [--part <1|2>] [--time] [--check] [--format <text|json>] [-j <n>] [--timeout <secs>] <days> [<input> | -]");
+    println!("       {exe} [--data <dir>] [--part <1|2>] [--time] --example [<name>] <days>");
+    println!("       {exe} bench [--data <dir>] [--part <1|2>] [--runs <n>] [--warmup <n>] [--save <file>] [--baseline <file>] [--threshold <pct>] <days> [<input> | -]");
+    println!("       {exe} new [--data <dir>] <day>");
+    println!("       {exe} fetch [--data <dir>] <days>");
+    println!("       {exe} submit [--data <dir>] <day> <1|2>");
+    println!("       {exe} watch [--data <dir>] [--part <1|2>] [--time] <day> [<input>]");
+    println!();
+    println!("  <days>        a day (1-25), a range (3-7), a list (1,5,9) or 'all' the registered days");
+    println!("  <input>       puzzle input file, '-' to read from stdin (single day only)");
+    println!("  --data <dir>  data root searched when no input is given (default: ${} or '{}')", input::DATA_ENV, input::DATA_DIR);
+    println!("  --part <1|2>  run only one part");
+    println!("  --time        report parse, part 1 and part 2 times separately");
+    println!("  --check       compare the answers with <data>/day_NN/answers.txt");
+    println!("  --format <f>  'text' (default) or 'json', one object per day and per line");
+    println!("  -j <n>        run the days on n threads (default: 1), reporting the wall time against");
+    println!("                the summed time of the days");
+    println!("  --timeout <s> report a part still running after s seconds as TIMEOUT and cancel it");
+    println!("  --example     run the examples of the days (or only <name>) and check their answers;");
+    println!("                an example is inline or in <data>/day_NN/<name>.txt");
+    println!();
+    println!("  bench               time the parse and each part over several runs");
+    println!("  --runs <n>          timed runs (default: 10)");
+    println!("  --warmup <n>        untimed runs before them (default: 1)");
+    println!("  --save <file>       save the medians as a baseline");
+    println!("  --baseline <file>   compare the medians with a saved baseline");
+    println!("  --threshold <pct>   slowdown flagged as a regression (default: 10)");
+    println!();
+    println!("  new                 add src/aoc/day_NN.rs from the day_xx.rs template, register it");
+    println!("                      and create the <data>/day_NN/input.txt and test.txt placeholders");
+    println!();
+    println!("  fetch               download the inputs missing under <data> from ${} or 'url' in", client::URL_ENV);
+    println!("                      <data>/config.txt, which must be set to an http:// URL (std has no TLS,");
+    println!("                      so use a local TLS proxy), with the session token from ${} or", client::SESSION_ENV);
+    println!("                      'session' in <data>/config.txt; a missing input is also fetched when");
+    println!("                      running a day once a session token is set");
+    println!();
+    println!("  submit              post the answer of a part to the same site and record the attempt");
+    println!("                      in <data>/submissions.txt; a known wrong answer, one beyond a known");
+    println!("                      too high/too low bound or one within the cooldown is not posted");
+    println!();
+    println!("  watch               run the day, then again whenever its input or answers.txt change, printing");
+    println!("                      the answers which changed since the previous run; a changed example file");
+    println!("                      runs that example and checks its expected answers");
     std::process::exit(0)
 }
 
+fn fail(message: &str) -> ! {
+    eprintln!("{message}");
+    std::process::exit(1)
+}
+
+// runs the parts with an answer, or both when the example has none
+fn run_examples(root: &std::path::Path, days: &[u8], name: Option<&str>, parts: &[u8], time: bool) -> bool {
+    let mut ok = true;
+    let mut found = false;
+    for &day in days {
+        let Some(solver) = aoc::get_solver(day) else { continue };
+        for example in solver.examples().iter().filter(|e| name.is_none_or(|n| e.name == n)) {
+            found = true;
+            let any = example.answers.iter().any(|a| a.is_some());
+            let parts: Vec<_> = parts.iter().copied().filter(|&p| !any || example.get_answer(p).is_some()).collect();
+            if parts.is_empty() {
+                continue;
+            }
+            let mut result = match input::read_example(root, day, example) {
+                Ok(data) => runner::quiet(|| runner::run_day(solver, &data, &parts)),
+                Err(e) => runner::DayResult::failed(day, format!("cannot read example '{}': {e}", example.name))
+            };
+            let answers: Vec<_> = (1..=2).filter_map(|p| example.get_answer(p).map(|a| (p, a.to_string()))).collect();
+            check::check_day(&mut result, &answers);
+            println!("day {day}, example {}:", example.name);
+            report::print_answers(&result, time);
+            ok &= result.is_ok() && (result.get_check() != Some(check::Check::Fail));
+        }
+    }
+    if !found {
+        eprintln!("no example{}", name.map_or(String::new(), |n| format!(" '{n}'")));
+    }
+    ok && found
+}
+
+fn submit_answer(config: &client::Config, root: &std::path::Path, day: u8, part: u8) -> Result<(), String> {
+    let result = runner::run_days(&[day], &[part], |day| {
+        let solver = aoc::get_solver(day).ok_or("not implemented")?;
+        let path = match input::find_input(root, day) {
+            Some(path) => path,
+            None => client::fetch_input(config, root, day)?.0
+        };
+        let data = std::fs::read_to_string(path).map_err(|e| format!("cannot read input: {e}"))?;
+        Ok((solver, data))
+    }).remove(0);
+    if let Some(e) = result.error {
+        return Err(e);
+    }
+    let answer = match &result.parts[0].status {
+        runner::Status::Ok(answer) => answer.to_string(),
+        runner::Status::Panic(e) => return Err(format!("panicked: {e}")),
+        runner::Status::Unimplemented => return Err("not implemented".to_string()),
+        runner::Status::Timeout => return Err("timed out".to_string())
+    };
+    let log = submit::load_log(root)?;
+    let now = || std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).map_or(0, |d| d.as_secs());
+    submit::check_attempt(&log, day, part, &answer, now()).map_err(|e| format!("not submitting {answer}, {e}"))?;
+    let (outcome, message) = submit::submit(config, day, part, &answer)?;
+    submit::append_attempt(root, &submit::Attempt {time: now(), day, part, outcome: outcome.clone(), answer: answer.clone()})?;
+    println!("day {day} part {part}: {answer}: {outcome}");
+    println!("{message}");
+    match outcome {
+        submit::Outcome::Correct => Ok(()),
+        _ => std::process::exit(1)
+    }
+}
+
 fn main() {
     let args: Vec<_> = std::env::args().collect();
-    if args.len() != 2 {
+    let command = args.get(1).map(|s| s.as_str()).filter(|s| ["bench", "new", "fetch", "submit", "watch"].contains(s)).unwrap_or("run");
+    let bench = command == "bench";
+    let mut runs = 10;
+    let mut warmup = 1;
+    let mut save = None;
+    let mut baseline = None;
+    let mut threshold = 10.0;
+    let mut root = None;
+    let mut parts = vec![1, 2];
+    let mut time = false;
+    let mut check = false;
+    let mut json = false;
+    let mut example = None;
+    let mut jobs = 1;
+    let mut timeout = None;
+    let mut positionals = Vec::new();
+    let mut i = if command == "run" {1} else {2};
+    while i < args.len() {
+        match args[i].as_str() {
+            "--data" => {
+                i += 1;
+                if i == args.len() {
+                    usage(&args[0]);
+                }
+                root = Some(args[i].as_str());
+            }
+            "--part" => {
+                i += 1;
+                match args.get(i).map(|s| s.as_str()) {
+                    Some("1") => parts = vec![1],
+                    Some("2") => parts = vec![2],
+                    _ => usage(&args[0])
+                }
+            }
+            "--time" => time = true,
+            "--check" => check = true,
+            "--format" => {
+                i += 1;
+                match args.get(i).map(|s| s.as_str()) {
+                    Some("text") => json = false,
+                    Some("json") => json = true,
+                    _ => usage(&args[0])
+                }
+            }
+            "-j" | "--jobs" if command == "run" => {
+                i += 1;
+                match args.get(i).and_then(|s| s.parse().ok()).filter(|&n| n > 0) {
+                    Some(n) => jobs = n,
+                    None => usage(&args[0])
+                }
+            }
+            "--timeout" if command == "run" => {
+                i += 1;
+                match args.get(i).and_then(|s| s.parse().ok()).filter(|&s: &f64| s.is_finite() && (s > 0.0)) {
+                    Some(s) => timeout = Some(std::time::Duration::from_secs_f64(s)),
+                    None => usage(&args[0])
+                }
+            }
+            "--example" if command == "run" => {
+                // the name is optional, the days following otherwise
+                match args.get(i + 1).filter(|s| !s.starts_with('-') && runner::parse_days(s).is_none()) {
+                    Some(name) => {
+                        i += 1;
+                        example = Some(Some(name.as_str()));
+                    }
+                    None => example = Some(None)
+                }
+            }
+            "--runs" if bench => {
+                i += 1;
+                match args.get(i).and_then(|s| s.parse().ok()) {
+                    Some(n) => runs = n,
+                    None => usage(&args[0])
+                }
+            }
+            "--warmup" if bench => {
+                i += 1;
+                match args.get(i).and_then(|s| s.parse().ok()) {
+                    Some(n) => warmup = n,
+                    None => usage(&args[0])
+                }
+            }
+            "--save" if bench => {
+                i += 1;
+                match args.get(i) {
+                    Some(path) => save = Some(std::path::Path::new(path)),
+                    None => usage(&args[0])
+                }
+            }
+            "--baseline" if bench => {
+                i += 1;
+                match args.get(i) {
+                    Some(path) => baseline = Some(std::path::Path::new(path)),
+                    None => usage(&args[0])
+                }
+            }
+            "--threshold" if bench => {
+                i += 1;
+                match args.get(i).and_then(|s| s.parse().ok()) {
+                    Some(pct) => threshold = pct,
+                    None => usage(&args[0])
+                }
+            }
+            "-h" | "--help" => usage(&args[0]),
+            arg => positionals.push(arg)
+        }
+        i += 1;
+    }
+    if command == "new" {
+        let day = match positionals[..] {
+            [day] => day.parse().ok().filter(|d| (1..=25).contains(d)),
+            _ => None
+        };
+        let day = day.unwrap_or_else(|| usage(&args[0]));
+        let src = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+        let created = scaffold::new_day(&src, &input::get_data_root(root), day).unwrap_or_else(|e| fail(&e));
+        created.iter().for_each(|p| println!("created {}", p.display()));
+        println!("registered day {day} in {}", src.join("aoc.rs").display());
+        return;
+    }
+    if command == "fetch" {
+        let days = match positionals[..] {
+            [days] => runner::parse_days(days),
+            _ => None
+        };
+        let days = days.unwrap_or_else(|| usage(&args[0]));
+        let root = input::get_data_root(root);
+        let config = client::load_config(&root).unwrap_or_else(|e| fail(&e));
+        let mut failed = false;
+        for day in days {
+            match client::fetch_input(&config, &root, day) {
+                Ok((path, true)) => println!("day {day}: fetched {}", path.display()),
+                Ok((path, false)) => println!("day {day}: cached in {}", path.display()),
+                Err(e) => {
+                    eprintln!("day {day}: {e}");
+                    failed = true;
+                }
+            }
+        }
+        if failed {
+            std::process::exit(1);
+        }
+        return;
+    }
+    if command == "submit" {
+        let (day, part) = match positionals[..] {
+            [day, part] => (day.parse().ok().filter(|d| (1..=25).contains(d)), part.parse().ok().filter(|p| (1..=2).contains(p))),
+            _ => (None, None)
+        };
+        let (Some(day), Some(part)) = (day, part) else { usage(&args[0]) };
+        let root = input::get_data_root(root);
+        let config = client::load_config(&root).unwrap_or_else(|e| fail(&e));
+        submit_answer(&config, &root, day, part).unwrap_or_else(|e| fail(&format!("day {day} part {part}: {e}")));
+        return;
+    }
+    if positionals.is_empty() || positionals.len() > 2 || runs == 0 {
         usage(&args[0]);
     }
 
-    let day: u8 = match args[1].parse() {
-        Ok(n) if (1..=25).contains(&n) => n,
-        _ => {
+    let days = match runner::parse_days(positionals[0]) {
+        Some(days) => days,
+        None => usage(&args[0])
+    };
+    let source = positionals.get(1).copied();
+    if (source.is_some() || (command == "watch")) && (days.len() != 1) {
+        usage(&args[0]);
+    }
+
+    let root = input::get_data_root(root);
+    if let Some(name) = example {
+        if source.is_some() {
             usage(&args[0]);
         }
+        if !run_examples(&root, &days, name, &parts, time) {
+            std::process::exit(1);
+        }
+        return;
+    }
+    let config = client::load_config(&root);
+    let load = |day| {
+        let solver = aoc::get_solver(day).ok_or("not implemented")?;
+        let data = match (input::read_input(source, &root, day), &config) {
+            // fetched when a session token is set
+            (Err(e), Ok(config)) if (e.kind() == std::io::ErrorKind::NotFound) && source.is_none() && config.session.is_some() => {
+                let (path, _) = client::fetch_input(config, &root, day)?;
+                std::fs::read_to_string(path).map_err(|e| format!("cannot read input: {e}"))?
+            }
+            (data, _) => data.map_err(|e| format!("cannot read input: {e}"))?
+        };
+        Ok((solver, data))
     };
-
-    match day {
-        1  => aoc::day_01::solve(),
-        2  => aoc::day_02::solve(),
-        3  => aoc::day_03::solve(),
-        4  => aoc::day_04::solve(),
-        5  => aoc::day_05::solve(),
-        6  => aoc::day_06::solve(),
-        7  => aoc::day_07::solve(),
-        8  => aoc::day_08::solve(),
-        9  => aoc::day_09::solve(),
-        10 => aoc::day_10::solve(),
-        11 => aoc::day_11::solve(),
-        12 => aoc::day_12::solve(),
-        13 => aoc::day_13::solve(),
-        14 => aoc::day_14::solve(),
-        15 => aoc::day_15::solve(),
-        16 => aoc::day_16::solve(),
-        17 => aoc::day_17::solve(),
-        18 => aoc::day_18::solve(),
-        19 => aoc::day_19::solve(),
-        20 => aoc::day_20::solve(),
-        21 => aoc::day_21::solve(),
-        22 => aoc::day_22::solve(),
-        23 => aoc::day_23::solve(),
-        24 => aoc::day_24::solve(),
-        25 => aoc::day_25::solve(),
-        _ => println!("day {day} not implemented")
+    if command == "watch" {
+        if source == Some("-") {
+            usage(&args[0]);
+        }
+        let day = days[0];
+        let examples = aoc::get_solver(day).map(|s| s.examples()).unwrap_or(&[]);
+        let paths = watch::get_watched(source, &root, day, examples);
+        println!("watching {}", paths.iter().map(|p| p.display().to_string()).collect::<Vec<_>>().join(", "));
+        watch::watch(&paths, |previous, changed| {
+            // a changed example file runs that example, any other change the day
+            let changed_examples = watch::get_changed_examples(&root, day, examples, changed);
+            for example in &changed_examples {
+                run_examples(&root, &[day], Some(example.name), &parts, time);
+            }
+            if let Some(previous) = previous.filter(|_| changed_examples.len() == changed.len()) {
+                return previous.clone();
+            }
+            let mut result = runner::run_days(&[day], &parts, &load).remove(0);
+            match check::load_answers(&root, day) {
+                Ok(answers) if !answers.is_empty() => check::check_day(&mut result, &answers),
+                Ok(_) => (),
+                Err(e) => eprintln!("day {day}: {e}")
+            }
+            report::print_answers(&result, time);
+            if let Some(previous) = previous {
+                let changes = watch::get_changes(previous, &result);
+                if changes.is_empty() {
+                    println!("no answer changed");
+                }
+                changes.iter().for_each(|c| println!("changed {c}"));
+            }
+            result
+        });
+    }
+    if bench {
+        let baseline = baseline.map(|path| bench::load_baseline(path).unwrap_or_else(|e| fail(&e)));
+        let threshold = threshold / 100.0;
+        let results = bench::bench_days(&days, &parts, warmup, runs, load);
+        bench::print_bench(&results, baseline.as_ref(), threshold);
+        if let Some(path) = save {
+            bench::save_baseline(path, &results).unwrap_or_else(|e| fail(&e));
+        }
+        let regressions = baseline.map_or(0, |b| bench::count_regressions(&results, &b, threshold));
+        if regressions > 0 {
+            println!();
+            println!("{regressions} regression(s) over {}%", threshold * 100.0);
+        }
+        if (regressions > 0) || results.iter().any(|r| r.error.is_some()) {
+            std::process::exit(1);
+        }
+        return;
+    }
+    let start = std::time::Instant::now();
+    let mut results = runner::run_days_parallel(&days, &parts, jobs, timeout, load);
+    let wall = start.elapsed();
+    if check {
+        for r in results.iter_mut() {
+            let answers = check::load_answers(&root, r.day).unwrap_or_else(|e| {
+                eprintln!("day {}: {e}", r.day);
+                Vec::new()
+            });
+            check::check_day(r, &answers);
+        }
+    }
+    if json {
+        report::print_json(&results);
+    } else {
+        if days.len() == 1 {
+            report::print_answers(&results[0], time);
+        } else {
+            report::print_table(&results, time);
+            report::print_total(&results, wall);
+        }
+        if check {
+            report::print_checks(&results);
+        }
+    }
+    if !results.iter().all(|r| r.is_ok() && (r.get_check() != Some(check::Check::Fail))) {
+        std::process::exit(1);
     }
 }
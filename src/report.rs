@@ -0,0 +1,214 @@
+use std::time::Duration;
+
+use crate::check::Check;
+use crate::runner::{DayResult, PartResult, Status};
+
+pub fn format_duration(d: Duration) -> String {
+    let ns = d.as_nanos();
+    if ns < 1_000 {
+        format!("{ns}ns")
+    } else if ns < 1_000_000 {
+        format!("{:.1}µs", ns as f64 / 1e3)
+    } else if ns < 1_000_000_000 {
+        format!("{:.1}ms", ns as f64 / 1e6)
+    } else {
+        format!("{:.2}s", ns as f64 / 1e9)
+    }
+}
+
+pub fn format_status(status: &Status) -> String {
+    match status {
+        Status::Ok(answer) => answer.to_string(),
+        Status::Panic(e) => format!("panicked: {e}"),
+        Status::Unimplemented => "unimplemented".to_string(),
+        Status::Timeout => "TIMEOUT".to_string()
+    }
+}
+
+fn format_check(p: &PartResult) -> String {
+    match (p.check, &p.expected) {
+        (Some(Check::Fail), Some(expected)) => format!("FAIL (expected {expected})"),
+        (Some(check), _) => check.to_string(),
+        (None, _) => String::new()
+    }
+}
+
+pub fn print_answers(result: &DayResult, time: bool) {
+    if let Some(e) = &result.error {
+        println!("day {}: {e}", result.day);
+        return;
+    }
+    if time {
+        println!("parse:  {}", format_duration(result.parse));
+    }
+    for p in &result.parts {
+        let mut line = format!("part {}: {}", p.part, format_status(&p.status));
+        if time {
+            line += &format!(" ({})", format_duration(p.elapsed));
+        }
+        if p.check.is_some() {
+            line += &format!(" {}", format_check(p));
+        }
+        println!("{line}");
+    }
+}
+
+pub fn print_table(results: &[DayResult], time: bool) {
+    let check = results.iter().any(|r| r.get_check().is_some());
+    let rows: Vec<_> = results.iter().flat_map(|r| {
+        let error = r.error.iter().map(|e| {
+            let check = if check {Check::Fail.to_string()} else {String::new()};
+            (r.day, String::new(), format!("error: {e}"), String::new(), check)
+        });
+        let parse = r.error.is_none() && time;
+        let parse = parse.then(|| (r.day, "parse".to_string(), String::new(), format_duration(r.parse), String::new()));
+        let parts = r.parts.iter().map(|p| {
+            (r.day, p.part.to_string(), format_status(&p.status), format_duration(p.elapsed), format_check(p))
+        });
+        error.chain(parse).chain(parts).collect::<Vec<_>>()
+    }).collect();
+    // errors span the answer and time columns
+    let timed = rows.iter().filter(|r| !r.3.is_empty());
+    let aw = timed.clone().map(|r| r.2.chars().count()).max().unwrap_or(0).max("answer".len());
+    let tw = timed.map(|r| r.3.chars().count()).max().unwrap_or(0).max("time".len());
+    let header = format!("day   part  {:aw$}  {:>tw$}", "answer", "time");
+    println!("{}", if check {header + "  check"} else {header});
+    for (day, part, answer, time, check) in rows {
+        let line = if time.is_empty() {
+            format!("{day:>3}  {part:>5}  {answer}")
+        } else {
+            format!("{day:>3}  {part:>5}  {answer:aw$}  {time:>tw$}")
+        };
+        if check.is_empty() {
+            println!("{line}");
+        } else {
+            println!("{line}  {check}");
+        }
+    }
+    let ok = results.iter().filter(|r| r.is_ok()).count();
+    println!();
+    println!("{ok}/{} days ok", results.len());
+}
+
+// the days' times summed, against the wall time when they ran in parallel, under the table
+pub fn print_total(results: &[DayResult], wall: Duration) {
+    let total: Duration = results.iter().map(|r| r.get_elapsed()).sum();
+    let speedup = total.as_secs_f64() / wall.as_secs_f64().max(f64::MIN_POSITIVE);
+    println!("total: {} wall, {} summed ({speedup:.1}x)", format_duration(wall), format_duration(total));
+}
+
+pub fn print_checks(results: &[DayResult]) {
+    let checks: Vec<_> = results.iter().filter_map(|r| r.get_check().map(|c| (r.day, c))).collect();
+    for c in [Check::Pass, Check::Fail, Check::Missing] {
+        let days: Vec<_> = checks.iter().filter(|(_, dc)| *dc == c).map(|(d, _)| d.to_string()).collect();
+        if !days.is_empty() {
+            println!("{c}: {}", days.join(", "));
+        }
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut json = String::with_capacity(s.len() + 2);
+    json.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c)
+        }
+    }
+    json.push('"');
+    json
+}
+
+fn json_optional(s: Option<&str>) -> String {
+    s.map(json_string).unwrap_or("null".to_string())
+}
+
+fn get_part_json(p: &PartResult) -> String {
+    let (status, answer, message) = match &p.status {
+        Status::Ok(answer) => ("ok", Some(answer.to_string()), None),
+        Status::Panic(e) => ("panic", None, Some(e.as_str())),
+        Status::Unimplemented => ("unimplemented", None, None),
+        Status::Timeout => ("timeout", None, None)
+    };
+    let mut json = format!(
+        "{{\"part\":{},\"answer\":{},\"status\":\"{status}\",\"duration_ns\":{}",
+        p.part,
+        json_optional(answer.as_deref()),
+        p.elapsed.as_nanos()
+    );
+    if let Some(message) = message {
+        json += &format!(",\"message\":{}", json_string(message));
+    }
+    if let Some(check) = p.check {
+        json += &format!(",\"check\":\"{check}\",\"expected\":{}", json_optional(p.expected.as_deref()));
+    }
+    json.push('}');
+    json
+}
+
+pub fn get_day_json(result: &DayResult) -> String {
+    let parts: Vec<_> = result.parts.iter().map(get_part_json).collect();
+    format!(
+        "{{\"day\":{},\"error\":{},\"parse_ns\":{},\"parts\":[{}]}}",
+        result.day,
+        json_optional(result.error.as_deref()),
+        result.parse.as_nanos(),
+        parts.join(",")
+    )
+}
+
+// one object per line
+pub fn print_json(results: &[DayResult]) {
+    for r in results {
+        println!("{}", get_day_json(r));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_2024::aoc::solution::Answer;
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(999)), "999ns");
+        assert_eq!(format_duration(Duration::from_nanos(12_345)), "12.3µs");
+        assert_eq!(format_duration(Duration::from_micros(4_560)), "4.6ms");
+        assert_eq!(format_duration(Duration::from_millis(2_500)), "2.50s");
+    }
+
+    #[test]
+    fn test_json_string() {
+        assert_eq!(json_string("co,de"), "\"co,de\"");
+        assert_eq!(json_string("a \"b\"\\\n"), "\"a \\\"b\\\"\\\\\\n\"");
+    }
+
+    #[test]
+    fn test_get_day_json() {
+        let part = |part, status| PartResult {elapsed: Duration::from_nanos(5), ..PartResult::new(part, status)};
+        let result = DayResult {
+            day: 15,
+            error: None,
+            parse: Duration::from_nanos(7),
+            parts: vec![
+                part(1, Status::Ok(Answer::Number(10092))),
+                part(2, Status::Unimplemented)
+            ]
+        };
+        assert_eq!(get_day_json(&result), concat!(
+            "{\"day\":15,\"error\":null,\"parse_ns\":7,\"parts\":[",
+            "{\"part\":1,\"answer\":\"10092\",\"status\":\"ok\",\"duration_ns\":5},",
+            "{\"part\":2,\"answer\":null,\"status\":\"unimplemented\",\"duration_ns\":5}",
+            "]}"
+        ));
+
+        let result = DayResult::failed(3, "invalid input".to_string());
+        assert_eq!(get_day_json(&result), "{\"day\":3,\"error\":\"invalid input\",\"parse_ns\":0,\"parts\":[]}");
+    }
+}
@@ -0,0 +1,363 @@
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc_2024::aoc::SOLVERS;
+use aoc_2024::aoc::cancel::{self, Token};
+use aoc_2024::aoc::parse::ParseError;
+use aoc_2024::aoc::solution::{Answer, Solver};
+use crate::check::Check;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Status {
+    Ok(Answer),
+    Panic(String),
+    Unimplemented,
+    Timeout
+}
+
+impl Status {
+    pub fn from_panic(message: String) -> Self {
+        // todo!() and unimplemented!() messages
+        if message.starts_with("not yet implemented") || message.starts_with("not implemented") {
+            Status::Unimplemented
+        } else {
+            Status::Panic(message)
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PartResult {
+    pub part: u8,
+    pub status: Status,
+    pub elapsed: Duration,
+    pub check: Option<Check>,
+    pub expected: Option<String>
+}
+
+impl PartResult {
+    // not run yet, so not timed or checked
+    pub fn new(part: u8, status: Status) -> Self {
+        Self {part, status, elapsed: Duration::ZERO, check: None, expected: None}
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct DayResult {
+    pub day: u8,
+    pub error: Option<String>,
+    pub parse: Duration,
+    pub parts: Vec<PartResult>
+}
+
+impl DayResult {
+    pub fn failed(day: u8, error: String) -> Self {
+        Self {day, error: Some(error), parse: Duration::ZERO, parts: Vec::new()}
+    }
+
+    // parse and parts, as measured on the thread which ran them
+    pub fn get_elapsed(&self) -> Duration {
+        self.parse + self.parts.iter().map(|p| p.elapsed).sum::<Duration>()
+    }
+
+    // unimplemented parts are not failures
+    pub fn is_ok(&self) -> bool {
+        self.error.is_none() && self.parts.iter().all(|p| !matches!(p.status, Status::Panic(_) | Status::Timeout))
+    }
+}
+
+fn get_panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+pub fn catch<R, F: FnOnce() -> R>(f: F) -> (Result<R, String>, Duration) {
+    let start = Instant::now();
+    let r = panic::catch_unwind(AssertUnwindSafe(f));
+    let elapsed = start.elapsed();
+    (r.map_err(|e| get_panic_message(&*e)), elapsed)
+}
+
+fn parse_day(solver: &dyn Solver, data: &str) -> Result<(Box<dyn Any + Send + Sync>, Duration), DayResult> {
+    let day = solver.day();
+    let (puzzle, parse) = catch(|| solver.parse(data));
+    match puzzle {
+        Ok(Ok(puzzle)) => Ok((puzzle, parse)),
+        // the day is already reported
+        Ok(Err(e)) => Err(DayResult::failed(day, format!("invalid input, {}", ParseError {day: None, ..e}))),
+        Err(e) => Err(DayResult::failed(day, format!("parse panicked: {e}")))
+    }
+}
+
+fn run_part(solver: &dyn Solver, puzzle: &dyn Any, part: u8) -> (Status, Duration) {
+    let (r, elapsed) = catch(|| {
+        if part == 1 {solver.part_1(puzzle)} else {solver.part_2(puzzle)}
+    });
+    let status = match r {
+        Ok(answer) => Status::Ok(answer),
+        Err(e) => Status::from_panic(e)
+    };
+    (status, elapsed)
+}
+
+pub fn run_day(solver: &dyn Solver, data: &str, parts: &[u8]) -> DayResult {
+    let (puzzle, parse) = match parse_day(solver, data) {
+        Ok(puzzle) => puzzle,
+        Err(r) => return r
+    };
+    let parts = parts.iter().map(|&part| {
+        let (status, elapsed) = run_part(solver, &*puzzle, part);
+        PartResult {elapsed, ..PartResult::new(part, status)}
+    }).collect();
+    DayResult {day: solver.day(), error: None, parse, parts}
+}
+
+// time left to a cancelled part to unwind before it is left running
+const GRACE: Duration = Duration::from_millis(100);
+
+// runs each part on its own thread, cancelling it after the timeout
+pub fn run_day_timeout(solver: &'static dyn Solver, data: &str, parts: &[u8], timeout: Duration) -> DayResult {
+    let (puzzle, parse) = match parse_day(solver, data) {
+        Ok(puzzle) => puzzle,
+        Err(r) => return r
+    };
+    let puzzle = Arc::new(puzzle);
+    let parts = parts.iter().map(|&part| {
+        let token = Token::new();
+        let (tx, rx) = mpsc::channel();
+        let (puzzle, t) = (puzzle.clone(), token.clone());
+        thread::spawn(move || {
+            cancel::set_token(Some(t));
+            let _ = tx.send(run_part(solver, &**puzzle, part));
+        });
+        let (status, elapsed) = rx.recv_timeout(timeout).unwrap_or_else(|_| {
+            token.cancel();
+            let _ = rx.recv_timeout(GRACE);
+            (Status::Timeout, timeout)
+        });
+        PartResult {elapsed, ..PartResult::new(part, status)}
+    }).collect();
+    DayResult {day: solver.day(), error: None, parse, parts}
+}
+
+// panics caught while running f are reported in the results instead of on stderr
+pub fn quiet<R, F: FnOnce() -> R>(f: F) -> R {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let r = f();
+    panic::set_hook(hook);
+    r
+}
+
+// runs each day in isolation
+pub fn run_days<F>(days: &[u8], parts: &[u8], mut load: F) -> Vec<DayResult>
+where F: FnMut(u8) -> Result<(&'static dyn Solver, String), String>
+{
+    quiet(|| days.iter().map(|&day| {
+        match load(day) {
+            Ok((solver, data)) => run_day(solver, &data, parts),
+            Err(e) => DayResult::failed(day, e)
+        }
+    }).collect())
+}
+
+// runs the days on up to `jobs` threads, each taking the next day to run, the results staying in day order
+pub fn run_days_parallel<F>(days: &[u8], parts: &[u8], jobs: usize, timeout: Option<Duration>, load: F) -> Vec<DayResult>
+where F: Fn(u8) -> Result<(&'static dyn Solver, String), String> + Sync
+{
+    let next = AtomicUsize::new(0);
+    let worker = || {
+        let mut results = Vec::new();
+        loop {
+            let i = next.fetch_add(1, Ordering::Relaxed);
+            let Some(&day) = days.get(i) else { return results };
+            let (r, _) = catch(|| match (load(day), timeout) {
+                (Ok((solver, data)), Some(timeout)) => run_day_timeout(solver, &data, parts, timeout),
+                (Ok((solver, data)), None) => run_day(solver, &data, parts),
+                (Err(e), _) => DayResult::failed(day, e)
+            });
+            results.push((i, r.unwrap_or_else(|e| DayResult::failed(day, format!("panicked: {e}")))));
+        }
+    };
+    let mut results: Vec<_> = quiet(|| thread::scope(|s| {
+        let workers: Vec<_> = (0..jobs.clamp(1, days.len().max(1))).map(|_| s.spawn(worker)).collect();
+        workers.into_iter().flat_map(|w| w.join().expect("panics caught")).collect()
+    }));
+    results.sort_by_key(|(i, _)| *i);
+    results.into_iter().map(|(_, r)| r).collect()
+}
+
+// "all" being every registered day
+pub fn parse_days(s: &str) -> Option<Vec<u8>> {
+    if s == "all" {
+        let mut days: Vec<_> = SOLVERS.iter().map(|s| s.day()).collect();
+        days.sort();
+        return Some(days);
+    }
+    let mut days = Vec::new();
+    for item in s.split(',') {
+        let (b, e) = match item.split_once('-') {
+            Some((b, e)) => (b.trim().parse().ok()?, e.trim().parse().ok()?),
+            None => {
+                let d = item.trim().parse().ok()?;
+                (d, d)
+            }
+        };
+        if (b < 1) || (e > 25) || (b > e) {
+            return None;
+        }
+        for d in b..=e {
+            if !days.contains(&d) {
+                days.push(d);
+            }
+        }
+    }
+    days.sort();
+    Some(days)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_2024::aoc::parse::parse_at;
+    use aoc_2024::aoc::solution::Solution;
+
+    struct Test;
+
+    impl Solution for Test {
+        const DAY: u8 = 0;
+
+        type Puzzle = u32;
+        type Part1 = u32;
+        type Part2 = u32;
+
+        fn parse(data: &str) -> Result<u32, ParseError> {
+            parse_at(data, data, "a number")
+        }
+
+        fn part_1(puzzle: &u32) -> u32 {
+            puzzle.checked_mul(2).expect("overflow")
+        }
+
+        fn part_2(_: &u32) -> u32 {
+            todo!("part 2")
+        }
+    }
+
+    struct Slow;
+
+    impl Solution for Slow {
+        const DAY: u8 = 0;
+
+        type Puzzle = u32;
+        type Part1 = u32;
+        type Part2 = u32;
+
+        fn parse(data: &str) -> Result<u32, ParseError> {
+            parse_at(data, data, "a number")
+        }
+
+        fn part_1(_: &u32) -> u32 {
+            loop {
+                cancel::check();
+                thread::sleep(Duration::from_millis(1));
+            }
+        }
+
+        fn part_2(puzzle: &u32) -> u32 {
+            *puzzle
+        }
+    }
+
+    #[test]
+    fn test_parse_days() {
+        let all = parse_days("all").unwrap();
+        assert_eq!(all.len(), SOLVERS.len());
+        assert!(all.windows(2).all(|w| w[0] < w[1]));
+        assert!(all.iter().all(|&d| aoc_2024::aoc::get_solver(d).is_some()));
+        assert_eq!(parse_days("3"), Some(vec![3]));
+        assert_eq!(parse_days("3-7"), Some(vec![3, 4, 5, 6, 7]));
+        assert_eq!(parse_days("9,1,5"), Some(vec![1, 5, 9]));
+        assert_eq!(parse_days("1,3-4,4"), Some(vec![1, 3, 4]));
+        assert_eq!(parse_days("0"), None);
+        assert_eq!(parse_days("20-26"), None);
+        assert_eq!(parse_days("7-3"), None);
+        assert_eq!(parse_days("1,x"), None);
+    }
+
+    #[test]
+    fn test_run_day() {
+        let r = run_day(&Test, "x", &[1, 2]);
+        assert_eq!(r.error, Some("invalid input, line 1, column 1: expected a number".to_string()));
+        assert!(!r.is_ok());
+
+        let r = run_day(&Test, "21", &[1, 2]);
+        assert!(r.error.is_none());
+        assert_eq!(r.parts.len(), 2);
+        assert_eq!(r.parts[0].status, Status::Ok(Answer::Number(42)));
+        assert_eq!(r.parts[1].status, Status::Unimplemented);
+        assert!(r.is_ok());
+
+        let r = run_day(&Test, "4294967295", &[1, 2]);
+        assert_eq!(r.parts[0].status, Status::Panic("overflow".to_string()));
+        assert!(!r.is_ok());
+
+        let r = run_day(&Test, "21", &[1]);
+        assert_eq!(r.parts.len(), 1);
+        assert_eq!(r.parts[0].part, 1);
+        assert!(r.is_ok());
+    }
+
+    #[test]
+    fn test_run_days() {
+        let rs = run_days(&[1, 2], &[1, 2], |day| {
+            if day == 1 {Ok((&Test, "1".to_string()))} else {Err("no input".to_string())}
+        });
+        assert_eq!(rs.len(), 2);
+        assert_eq!(rs[0].parts[0].status, Status::Ok(Answer::Number(2)));
+        assert_eq!(rs[0].parts[1].status, Status::Unimplemented);
+        assert_eq!(rs[1].day, 2);
+        assert_eq!(rs[1].error, Some("no input".to_string()));
+    }
+
+    #[test]
+    fn test_run_days_parallel() {
+        let load = |day| match day {
+            3 => Err("no input".to_string()),
+            5 => panic!("cannot load"),
+            _ => Ok((&Test as &dyn Solver, day.to_string()))
+        };
+        let days: Vec<_> = (1..=12).collect();
+        for jobs in [1, 4, 20] {
+            let rs = run_days_parallel(&days, &[1], jobs, None, load);
+            assert_eq!(rs.len(), 12);
+            for (i, r) in rs.iter().enumerate().filter(|(i, _)| ![2, 4].contains(i)) {
+                assert_eq!(r.parts[0].status, Status::Ok(Answer::Number(2 * (i as i128 + 1))));
+            }
+            assert_eq!(rs[2].error, Some("no input".to_string()));
+            assert_eq!(rs[4].error, Some("panicked: cannot load".to_string()));
+        }
+        assert!(run_days_parallel(&[], &[1], 4, None, load).is_empty());
+    }
+
+    #[test]
+    fn test_run_day_timeout() {
+        let r = quiet(|| run_day_timeout(&Slow, "7", &[1, 2], Duration::from_millis(50)));
+        assert_eq!(r.parts[0].status, Status::Timeout);
+        assert_eq!(r.parts[0].elapsed, Duration::from_millis(50));
+        assert_eq!(r.parts[1].status, Status::Ok(Answer::Number(7)));
+        assert!(!r.is_ok());
+
+        let r = run_day_timeout(&Test, "x", &[1], Duration::from_millis(50));
+        assert!(r.error.is_some());
+    }
+}
@@ -0,0 +1,140 @@
+use std::path::{Path, PathBuf};
+
+use crate::input::get_day_dir;
+
+const TEMPLATE: &str = include_str!("aoc/day_xx.rs");
+
+pub fn get_module(day: u8) -> String {
+    TEMPLATE
+        .replace("day_xx", &format!("day_{day:02}"))
+        .replace("DayXX", &format!("Day{day:02}"))
+        .replace("const DAY: u8 = 0;", &format!("const DAY: u8 = {day};"))
+}
+
+fn get_day(s: &str, prefix: &str, suffix: &str) -> Option<u8> {
+    s.trim().strip_prefix(prefix)?.strip_suffix(suffix)?.parse().ok()
+}
+
+// adds the module declaration and the solver of the day to the aoc.rs source, keeping both sorted
+pub fn register_module(aoc: &str, day: u8) -> Result<String, String> {
+    let lines: Vec<_> = aoc.lines().collect();
+    let mods: Vec<_> = lines.iter().enumerate().filter_map(|(i, s)| get_day(s, "pub mod day_", ";").map(|d| (i, d))).collect();
+    if mods.iter().any(|(_, d)| *d == day) {
+        return Err(format!("day {day} is already registered"));
+    }
+    let start = lines.iter().position(|s| s.starts_with("pub const SOLVERS")).ok_or("no SOLVERS in aoc.rs")?;
+    let end = start + lines[start..].iter().position(|s| s.trim() == "];").ok_or("no end of SOLVERS in aoc.rs")?;
+
+    let mut days: Vec<_> = mods.iter().map(|(_, d)| *d).chain([day]).collect();
+    days.sort();
+    let solvers: Vec<_> = days.iter().map(|d| format!("    &day_{d:02}::Day{d:02}")).collect();
+    let module_lines = format!("#[cfg(feature = \"days\")]\npub mod day_{day:02};");
+    // before the next day and its cfg attribute, or else after the previous day or the other modules
+    let next = mods.iter().find(|(_, d)| *d > day).map(|(i, _)| *i - 1);
+    let previous = mods.iter().rfind(|(_, d)| *d < day).map(|(i, _)| *i + 1);
+    let others = lines.iter().rposition(|s| s.starts_with("pub mod ")).map(|i| i + 1);
+    let at = next.or(previous).or(others).ok_or("no modules in aoc.rs")?;
+
+    let mut module = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        if (i > start) && (i < end) {
+            continue;
+        }
+        if i == at {
+            module.push(module_lines.clone());
+        }
+        if i == end {
+            module.push(solvers.join(",\n"));
+        }
+        module.push(line.to_string());
+    }
+    Ok(module.join("\n") + "\n")
+}
+
+fn create(path: PathBuf, data: &str, created: &mut Vec<PathBuf>) -> Result<(), String> {
+    if path.exists() {
+        return Ok(());
+    }
+    std::fs::write(&path, data).map_err(|e| format!("cannot write '{}': {e}", path.display()))?;
+    created.push(path);
+    Ok(())
+}
+
+// writes src/aoc/day_NN.rs and the data placeholders, returning the created files
+pub fn new_day(src: &Path, root: &Path, day: u8) -> Result<Vec<PathBuf>, String> {
+    let module = src.join("aoc").join(format!("day_{day:02}.rs"));
+    if module.exists() {
+        return Err(format!("'{}' already exists", module.display()));
+    }
+    let aoc = src.join("aoc.rs");
+    let registered = std::fs::read_to_string(&aoc).map_err(|e| format!("cannot read '{}': {e}", aoc.display()))?;
+    let registered = register_module(&registered, day)?;
+
+    let dir = get_day_dir(root, day);
+    std::fs::create_dir_all(&dir).map_err(|e| format!("cannot create '{}': {e}", dir.display()))?;
+    let mut created = Vec::new();
+    create(module, &get_module(day), &mut created)?;
+    create(dir.join("input.txt"), "", &mut created)?;
+    create(dir.join("test.txt"), "", &mut created)?;
+    std::fs::write(&aoc, registered).map_err(|e| format!("cannot write '{}': {e}", aoc.display()))?;
+    Ok(created)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AOC: &str = "pub mod grid;
+pub mod solution;
+
+#[cfg(feature = \"days\")]
+pub mod day_01;
+#[cfg(feature = \"days\")]
+pub mod day_03;
+
+#[cfg(feature = \"days\")]
+use solution::Solver;
+
+#[cfg(feature = \"days\")]
+pub const SOLVERS: &[&dyn Solver] = &[
+    &day_01::Day01,
+    &day_03::Day03
+];
+";
+
+    fn get_mods(days: &[u8]) -> String {
+        days.iter().map(|d| format!("#[cfg(feature = \"days\")]\npub mod day_{d:02};\n")).collect()
+    }
+
+    #[test]
+    fn test_get_module() {
+        let module = get_module(7);
+        assert!(module.contains("pub struct Day07;"));
+        assert!(module.contains("const DAY: u8 = 7;"));
+        assert!(module.contains("include_str!(\"../../data/day_07/test.txt\")"));
+        assert!(!module.contains("xx") && !module.contains("XX"));
+    }
+
+    #[test]
+    fn test_register_module() {
+        let aoc = register_module(AOC, 2).unwrap();
+        assert!(aoc.contains(&get_mods(&[1, 2, 3])));
+        assert!(aoc.contains("    &day_01::Day01,\n    &day_02::Day02,\n    &day_03::Day03\n];\n"));
+
+        let aoc = register_module(&aoc, 25).unwrap();
+        assert!(aoc.contains(&(get_mods(&[1, 2, 3, 25]) + "\n")));
+        assert!(aoc.contains("    &day_03::Day03,\n    &day_25::Day25\n];\n"));
+
+        let aoc = register_module(AOC, 0).unwrap();
+        assert!(aoc.contains(&("\n\n".to_string() + &get_mods(&[0, 1, 3]))));
+
+        assert_eq!(register_module(AOC, 3), Err("day 3 is already registered".to_string()));
+    }
+
+    #[test]
+    fn test_register_first_module() {
+        let aoc = "pub mod solution;\n\nuse solution::Solver;\n\npub const SOLVERS: &[&dyn Solver] = &[\n];\n";
+        let aoc = register_module(aoc, 1).unwrap();
+        assert_eq!(aoc, "pub mod solution;\n".to_string() + &get_mods(&[1]) + "\nuse solution::Solver;\n\npub const SOLVERS: &[&dyn Solver] = &[\n    &day_01::Day01\n];\n");
+    }
+}
@@ -0,0 +1,271 @@
+use std::path::{Path, PathBuf};
+
+use crate::client::Config;
+use crate::http;
+
+// seconds to wait after a wrong answer, when the site didn't say
+pub const COOLDOWN: u64 = 60;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Outcome {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    // seconds left to wait
+    Wait(u64),
+    Unknown
+}
+
+impl Outcome {
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Outcome::Incorrect | Outcome::TooHigh | Outcome::TooLow)
+    }
+}
+
+impl std::fmt::Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::Incorrect => write!(f, "incorrect"),
+            Outcome::TooHigh => write!(f, "too-high"),
+            Outcome::TooLow => write!(f, "too-low"),
+            Outcome::Wait(s) => write!(f, "wait-{s}"),
+            Outcome::Unknown => write!(f, "unknown")
+        }
+    }
+}
+
+impl std::str::FromStr for Outcome {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, ()> {
+        match s {
+            "correct" => Ok(Outcome::Correct),
+            "incorrect" => Ok(Outcome::Incorrect),
+            "too-high" => Ok(Outcome::TooHigh),
+            "too-low" => Ok(Outcome::TooLow),
+            "unknown" => Ok(Outcome::Unknown),
+            s => Ok(Outcome::Wait(s.strip_prefix("wait-").ok_or(())?.parse().map_err(|_| ())?))
+        }
+    }
+}
+
+// the text of the <article> holding the message, without its tags
+pub fn get_message(html: &str) -> String {
+    let html = match (html.find("<article"), html.find("</article>")) {
+        (Some(b), Some(e)) if b < e => &html[b..e],
+        _ => html
+    };
+    let mut message = String::new();
+    let mut tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => tag = true,
+            '>' if tag => tag = false,
+            c if !tag => message.push(c),
+            _ => ()
+        }
+    }
+    message.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+// "You have 1m 23s left to wait."
+fn get_wait(message: &str) -> Option<u64> {
+    let b = message.find("You have ")? + "You have ".len();
+    let e = b + message[b..].find(" left to wait")?;
+    message[b..e].split_whitespace().map(|t| {
+        let (n, unit) = t.split_at(t.find(|c: char| !c.is_ascii_digit())?);
+        let n: u64 = n.parse().ok()?;
+        match unit {
+            "h" => Some(n * 3600),
+            "m" => Some(n * 60),
+            "s" => Some(n),
+            _ => None
+        }
+    }).sum()
+}
+
+pub fn parse_outcome(message: &str) -> Outcome {
+    if message.contains("That's the right answer") {
+        Outcome::Correct
+    } else if message.contains("That's not the right answer") {
+        if message.contains("too high") {
+            Outcome::TooHigh
+        } else if message.contains("too low") {
+            Outcome::TooLow
+        } else {
+            Outcome::Incorrect
+        }
+    } else if message.contains("You gave an answer too recently") {
+        Outcome::Wait(get_wait(message).unwrap_or(COOLDOWN))
+    } else {
+        Outcome::Unknown
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Attempt {
+    // seconds since the epoch
+    pub time: u64,
+    pub day: u8,
+    pub part: u8,
+    pub outcome: Outcome,
+    pub answer: String
+}
+
+pub fn get_log_path(root: &Path) -> PathBuf {
+    root.join("submissions.txt")
+}
+
+// "<time> <day> <part> <outcome> <answer>" lines
+pub fn parse_log(data: &str) -> Option<Vec<Attempt>> {
+    data.lines().filter(|s| !s.trim().is_empty()).map(|s| {
+        let mut fields = s.splitn(5, ' ');
+        let time = fields.next()?.parse().ok()?;
+        let day = fields.next()?.parse().ok()?;
+        let part = fields.next()?.parse().ok()?;
+        let outcome = fields.next()?.parse().ok()?;
+        let answer = fields.next()?.to_string();
+        Some(Attempt {time, day, part, outcome, answer})
+    }).collect()
+}
+
+pub fn load_log(root: &Path) -> Result<Vec<Attempt>, String> {
+    let path = get_log_path(root);
+    match std::fs::read_to_string(&path) {
+        Ok(data) => parse_log(&data).ok_or_else(|| format!("invalid submissions in '{}'", path.display())),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(format!("cannot read '{}': {e}", path.display()))
+    }
+}
+
+pub fn append_attempt(root: &Path, attempt: &Attempt) -> Result<(), String> {
+    use std::io::Write;
+    let path = get_log_path(root);
+    let line = format!("{} {} {} {} {}\n", attempt.time, attempt.day, attempt.part, attempt.outcome, attempt.answer);
+    let file = std::fs::OpenOptions::new().create(true).append(true).open(&path);
+    file.and_then(|mut f| f.write_all(line.as_bytes())).map_err(|e| format!("cannot write '{}': {e}", path.display()))
+}
+
+// why the answer must not be submitted at `now`, given the previous attempts
+pub fn check_attempt(log: &[Attempt], day: u8, part: u8, answer: &str, now: u64) -> Result<(), String> {
+    let attempts: Vec<_> = log.iter().filter(|a| (a.day == day) && (a.part == part)).collect();
+    if let Some(a) = attempts.iter().find(|a| a.outcome == Outcome::Correct) {
+        return Err(format!("already solved with {}", a.answer));
+    }
+    if let Some(a) = attempts.iter().find(|a| a.outcome.is_wrong() && (a.answer == answer)) {
+        return Err(format!("{answer} was already submitted ({})", a.outcome));
+    }
+    // answers beyond a known bound are wrong too
+    if let Ok(n) = answer.parse::<i128>() {
+        for a in &attempts {
+            match (&a.outcome, a.answer.parse::<i128>()) {
+                (Outcome::TooHigh, Ok(high)) if n >= high => return Err(format!("{answer} is too high, {high} already was")),
+                (Outcome::TooLow, Ok(low)) if n <= low => return Err(format!("{answer} is too low, {low} already was")),
+                _ => ()
+            }
+        }
+    }
+    let until = log.iter().filter_map(|a| match a.outcome {
+        Outcome::Wait(s) => Some(a.time + s),
+        ref o if o.is_wrong() => Some(a.time + COOLDOWN),
+        _ => None
+    }).max();
+    match until {
+        Some(until) if now < until => Err(format!("wait {}s before submitting again", until - now)),
+        _ => Ok(())
+    }
+}
+
+// posts the answer, returning the outcome and the message of the site
+pub fn submit(config: &Config, day: u8, part: u8, answer: &str) -> Result<(Outcome, String), String> {
+    let cookie = config.get_cookie()?;
+    let headers = [("Cookie", cookie.as_str()), ("Content-Type", "application/x-www-form-urlencoded")];
+    let form = format!("level={part}&answer={}", encode(answer));
+    let response = http::request("POST", &config.get_url(&format!("/day/{day}/answer"))?, &headers, &form)?;
+    if response.status != 200 {
+        let message = response.body.lines().next().unwrap_or("").trim().to_string();
+        return Err(format!("cannot submit: HTTP {} {message}", response.status));
+    }
+    let message = get_message(&response.body);
+    Ok((parse_outcome(&message), message))
+}
+
+fn encode(s: &str) -> String {
+    s.bytes().map(|b| match b {
+        b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (b as char).to_string(),
+        b => format!("%{b:02X}")
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::mock;
+
+    fn attempt(time: u64, part: u8, outcome: Outcome, answer: &str) -> Attempt {
+        Attempt {time, day: 5, part, outcome, answer: answer.to_string()}
+    }
+
+    #[test]
+    fn test_parse_outcome() {
+        let html = "<main>\n<article><p>That's the right answer!  You are <em>one gold star</em> closer.</p></article>\n</main>";
+        assert_eq!(get_message(html), "That's the right answer! You are one gold star closer.");
+        assert_eq!(parse_outcome(&get_message(html)), Outcome::Correct);
+        let message = "That's not the right answer; your answer is too high. Please wait one minute before trying again.";
+        assert_eq!(parse_outcome(message), Outcome::TooHigh);
+        assert_eq!(parse_outcome(&message.replace("high", "low")), Outcome::TooLow);
+        assert_eq!(parse_outcome("That's not the right answer. Please wait one minute."), Outcome::Incorrect);
+        assert_eq!(parse_outcome("You gave an answer too recently. You have 1m 23s left to wait."), Outcome::Wait(83));
+        assert_eq!(parse_outcome("You gave an answer too recently. You have 35s left to wait."), Outcome::Wait(35));
+        assert_eq!(parse_outcome("You gave an answer too recently."), Outcome::Wait(COOLDOWN));
+        assert_eq!(parse_outcome("You don't seem to be solving the right level."), Outcome::Unknown);
+    }
+
+    #[test]
+    fn test_log() {
+        let log = vec![attempt(100, 1, Outcome::TooHigh, "6951"), attempt(200, 2, Outcome::Wait(83), "co,de ka")];
+        let data: String = log.iter().map(|a| format!("{} {} {} {} {}\n", a.time, a.day, a.part, a.outcome, a.answer)).collect();
+        assert_eq!(data, "100 5 1 too-high 6951\n200 5 2 wait-83 co,de ka\n");
+        assert_eq!(parse_log(&data), Some(log));
+        assert_eq!(parse_log("100 5 1 maybe 6951"), None);
+    }
+
+    #[test]
+    fn test_check_attempt() {
+        let log = vec![
+            attempt(100, 1, Outcome::TooHigh, "6951"),
+            attempt(200, 1, Outcome::TooLow, "4000"),
+            attempt(300, 1, Outcome::Incorrect, "5000")
+        ];
+        assert_eq!(check_attempt(&log, 5, 1, "5000", 1000), Err("5000 was already submitted (incorrect)".to_string()));
+        assert_eq!(check_attempt(&log, 5, 1, "7000", 1000), Err("7000 is too high, 6951 already was".to_string()));
+        assert_eq!(check_attempt(&log, 5, 1, "4000", 1000), Err("4000 was already submitted (too-low)".to_string()));
+        assert_eq!(check_attempt(&log, 5, 1, "3999", 1000), Err("3999 is too low, 4000 already was".to_string()));
+        assert_eq!(check_attempt(&log, 5, 1, "4321", 1000), Ok(()));
+        assert_eq!(check_attempt(&log, 5, 1, "4321", 330), Err("wait 30s before submitting again".to_string()));
+        assert_eq!(check_attempt(&log, 5, 2, "4321", 330), Err("wait 30s before submitting again".to_string()));
+
+        let log = vec![attempt(100, 1, Outcome::Wait(500), "4321"), attempt(200, 1, Outcome::Correct, "4321")];
+        assert_eq!(check_attempt(&log, 5, 1, "4321", 1000), Err("already solved with 4321".to_string()));
+        assert_eq!(check_attempt(&log, 5, 2, "1", 599), Err("wait 1s before submitting again".to_string()));
+        assert_eq!(check_attempt(&log, 5, 2, "1", 600), Ok(()));
+    }
+
+    #[test]
+    fn test_submit() {
+        let html = "<article><p>That's not the right answer; your answer is too low.</p></article>";
+        let (url, server) = mock::serve(vec![mock::response(200, html), mock::response(500, "error\n")]);
+        let config = Config {url: Some(format!("{url}/2024")), session: Some("53616c".to_string())};
+        let (outcome, message) = submit(&config, 5, 2, "co,de").unwrap();
+        assert_eq!(outcome, Outcome::TooLow);
+        assert_eq!(message, "That's not the right answer; your answer is too low.");
+        assert_eq!(submit(&config, 5, 2, "1"), Err("cannot submit: HTTP 500 error".to_string()));
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2024/day/5/answer HTTP/1.1\r\n"));
+        assert!(requests[0].contains("\r\nCookie: session=53616c\r\n"));
+        assert!(requests[0].ends_with("\r\n\r\nlevel=2&answer=co%2Cde"));
+    }
+}
@@ -0,0 +1,151 @@
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use aoc_2024::aoc::solution::Example;
+use crate::check::get_answers_path;
+use crate::input::get_day_dir;
+use crate::report::format_status;
+use crate::runner::DayResult;
+
+pub const INTERVAL: Duration = Duration::from_millis(500);
+
+// the input, wherever it may appear, the example files and the answers of the day
+pub fn get_watched(source: Option<&str>, root: &Path, day: u8, examples: &[Example]) -> Vec<PathBuf> {
+    let inputs = match source {
+        Some(path) => vec![PathBuf::from(path)],
+        None => vec![get_day_dir(root, day).join("input.txt"), root.join(format!("day_{day:02}.txt"))]
+    };
+    let examples = examples.iter().filter(|e| e.input.is_none()).map(|e| {
+        get_day_dir(root, day).join(format!("{}.txt", e.name))
+    });
+    inputs.into_iter().chain(examples).chain([get_answers_path(root, day)]).collect()
+}
+
+// the examples whose files are among the changed paths
+pub fn get_changed_examples<'a>(root: &Path, day: u8, examples: &'a [Example], changed: &[&Path]) -> Vec<&'a Example> {
+    examples.iter().filter(|e| e.input.is_none()).filter(|e| {
+        changed.contains(&get_day_dir(root, day).join(format!("{}.txt", e.name)).as_path())
+    }).collect()
+}
+
+// None for a missing file, so that creating or removing one is a change too
+pub fn get_mtimes(paths: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    paths.iter().map(|p| std::fs::metadata(p).and_then(|m| m.modified()).ok()).collect()
+}
+
+pub fn get_changed<'a>(paths: &'a [PathBuf], before: &[Option<SystemTime>], after: &[Option<SystemTime>]) -> Vec<&'a Path> {
+    paths.iter().zip(before.iter().zip(after)).filter(|(_, (b, a))| b != a).map(|(p, _)| p.as_path()).collect()
+}
+
+// "part 1: 11 -> 12" for each part whose answer changed
+pub fn get_changes(previous: &DayResult, current: &DayResult) -> Vec<String> {
+    let mut changes = Vec::new();
+    if previous.error != current.error {
+        let format = |r: &DayResult| r.error.clone().unwrap_or("no error".to_string());
+        changes.push(format!("error: {} -> {}", format(previous), format(current)));
+    }
+    for p in &current.parts {
+        let before = previous.parts.iter().find(|b| b.part == p.part).map(|b| format_status(&b.status));
+        let after = format_status(&p.status);
+        match before {
+            Some(before) if before == after => (),
+            Some(before) => changes.push(format!("part {}: {before} -> {after}", p.part)),
+            None => changes.push(format!("part {}: -> {after}", p.part))
+        }
+    }
+    changes
+}
+
+// runs the day, then again with the changed paths each time one of them changes, never returning
+pub fn watch<F>(paths: &[PathBuf], mut run: F) -> !
+where F: FnMut(Option<&DayResult>, &[&Path]) -> DayResult
+{
+    let mut mtimes = get_mtimes(paths);
+    let mut previous = run(None, &[]);
+    loop {
+        std::thread::sleep(INTERVAL);
+        let current = get_mtimes(paths);
+        let changed = get_changed(paths, &mtimes, &current);
+        if changed.is_empty() {
+            continue;
+        }
+        mtimes = current;
+        println!();
+        changed.iter().for_each(|p| println!("changed {}", p.display()));
+        previous = run(Some(&previous), &changed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_2024::aoc::solution::Answer;
+    use crate::input::temp_root;
+    use crate::runner::{PartResult, Status};
+
+    fn result(answers: &[(u8, Status)]) -> DayResult {
+        let parts = answers.iter().map(|(part, status)| PartResult::new(*part, status.clone())).collect();
+        DayResult {day: 1, error: None, parse: Duration::ZERO, parts}
+    }
+
+    #[test]
+    fn test_get_changed() {
+        let root = temp_root("watch");
+        std::fs::create_dir_all(get_day_dir(&root, 1)).unwrap();
+        let paths = get_watched(None, &root, 1, &[]);
+        assert_eq!(paths.len(), 3);
+
+        let before = get_mtimes(&paths);
+        assert_eq!(before, vec![None, None, None]);
+        std::fs::write(&paths[0], "3   4\n").unwrap();
+        let after = get_mtimes(&paths);
+        assert_eq!(get_changed(&paths, &before, &after), vec![paths[0].as_path()]);
+        assert!(get_changed(&paths, &after, &get_mtimes(&paths)).is_empty());
+        std::fs::remove_file(&paths[0]).unwrap();
+        assert_eq!(get_changed(&paths, &after, &get_mtimes(&paths)), vec![paths[0].as_path()]);
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_get_watched() {
+        let root = Path::new("data");
+        let examples = [
+            Example::new("test_1", Some("2028"), None),
+            Example::inline("small", "#@#", Some("0"), None),
+            Example::new("test_2", Some("10092"), None)
+        ];
+        let paths = get_watched(None, root, 15, &examples);
+        let day_dir = get_day_dir(root, 15);
+        assert_eq!(paths, vec![
+            day_dir.join("input.txt"),
+            root.join("day_15.txt"),
+            day_dir.join("test_1.txt"),
+            day_dir.join("test_2.txt"),
+            get_answers_path(root, 15)
+        ]);
+        let paths = get_watched(Some("other.txt"), root, 15, &examples[..1]);
+        assert_eq!(paths, vec![PathBuf::from("other.txt"), day_dir.join("test_1.txt"), get_answers_path(root, 15)]);
+    }
+
+    #[test]
+    fn test_get_changed_examples() {
+        let root = Path::new("data");
+        let examples = [Example::new("test_1", Some("2028"), None), Example::new("test_2", Some("10092"), None)];
+        let day_dir = get_day_dir(root, 15);
+        let (input, test_2) = (day_dir.join("input.txt"), day_dir.join("test_2.txt"));
+        let changed = get_changed_examples(root, 15, &examples, &[input.as_path(), test_2.as_path()]);
+        assert_eq!(changed.iter().map(|e| e.name).collect::<Vec<_>>(), vec!["test_2"]);
+        assert!(get_changed_examples(root, 15, &examples, &[input.as_path()]).is_empty());
+    }
+
+    #[test]
+    fn test_get_changes() {
+        let previous = result(&[(1, Status::Ok(Answer::Number(11))), (2, Status::Unimplemented)]);
+        let current = result(&[(1, Status::Ok(Answer::Number(11))), (2, Status::Ok(Answer::Number(31)))]);
+        assert_eq!(get_changes(&previous, &current), vec!["part 2: unimplemented -> 31"]);
+        assert!(get_changes(&current, &current).is_empty());
+        let failed = DayResult::failed(1, "invalid input".to_string());
+        assert_eq!(get_changes(&current, &failed), vec!["error: no error -> invalid input"]);
+        assert_eq!(get_changes(&failed, &current), vec!["error: invalid input -> no error", "part 1: -> 11", "part 2: -> 31"]);
+    }
+}
@@ -0,0 +1,55 @@
+use aoc_2024::grid::Grid;
+use aoc_2024::maze::Maze;
+use aoc_2024::parse::ParseError;
+use aoc_2024::sep::SepIterator;
+
+#[test]
+fn test_grid() {
+    let grid: Grid<char> = "ab\ncd".parse().unwrap();
+    assert_eq!(grid.size(), (2, 2));
+    assert_eq!(grid.find('c'), Some((0, 1)));
+    let grid: Result<Grid<char>, _> = "ab\nc".parse();
+    assert_eq!(grid.err(), Some(ParseError::new(2, 1, "a row of 2 cells")));
+}
+
+#[test]
+fn test_maze() {
+    let maze: Maze = "S.#\n#.#\n#.E".parse().unwrap();
+    assert_eq!(maze.get_distance(maze.start().unwrap(), maze.end().unwrap()), Some(4));
+}
+
+#[test]
+fn test_sep() {
+    let s: String = ["a", "b"].into_iter().map(String::from).sep(", ").collect();
+    assert_eq!(s, "a, b");
+}
+
+#[cfg(feature = "days")]
+#[test]
+fn test_solver() {
+    use aoc_2024::aoc::{day_01::Day01, get_solver};
+    use aoc_2024::solution::{Answer, Solution};
+
+    let puzzle = Day01::parse("3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n").unwrap();
+    assert_eq!(Day01::part_1(&puzzle), 11);
+    let solver = get_solver(1).unwrap();
+    let puzzle = solver.parse("3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n").unwrap();
+    assert_eq!(solver.part_2(&*puzzle), Answer::Number(31));
+}
+
+#[test]
+fn test_point() {
+    use aoc_2024::grid::{Direction, Point};
+    let p = Point::new(1, 1).step(Direction::try_from('>').unwrap());
+    assert_eq!(p, Point::new(2, 1));
+    assert_eq!(p.step_in(Direction::East, (3, 3)), None);
+}
+
+#[test]
+fn test_search() {
+    use aoc_2024::search::dijkstra;
+    let search = dijkstra([0u32], |n| [(n + 1, 1), (n * 2, 1)], |n| n == 10);
+    assert_eq!(search.distance(), Some(5));
+    assert_eq!(search.path().unwrap().first(), Some(&0));
+    assert_eq!(search.path().unwrap().len(), 6);
+}